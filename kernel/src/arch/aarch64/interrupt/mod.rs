@@ -377,10 +377,15 @@ pub extern "C" fn rust_irq_handler() {
         // Lower priority value = higher priority
         let was_preemption = new_priority < old_priority;
         
-        // Record statistics
+        // Record statistics. `recorded` tracks whether `record_irq` actually
+        // ran so the matching `exit_irq` below can be skipped when it
+        // didn't — otherwise exit_irq would pop the nesting depth and the
+        // `active` entry belonging to an unrelated, genuinely-nested IRQ.
+        let entry_us = crate::drivers::gic::stats::now_us();
+        let recorded;
         {
             let mut stats = crate::drivers::gic::stats::IRQ_STATS.lock();
-            
+
             // Check nesting depth for safety
             if stats.current_nesting >= MAX_IRQ_NESTING_DEPTH {
                 crate::debug!(
@@ -388,8 +393,10 @@ pub extern "C" fn rust_irq_handler() {
                     MAX_IRQ_NESTING_DEPTH
                 );
                 // Don't enable interrupts if we're at max depth
+                recorded = false;
             } else {
-                stats.record_irq(intid, was_preemption);
+                stats.record_irq(intid, was_preemption, entry_us);
+                recorded = true;
             }
         }
         
@@ -417,10 +424,13 @@ pub extern "C" fn rust_irq_handler() {
             
             // End of interrupt (EOI)
             crate::drivers::gic::write_eoi(iar);
-            
-            // Record exit from interrupt handler
-            crate::drivers::gic::stats::IRQ_STATS.lock().exit_irq();
-            
+
+            // Record exit from interrupt handler, but only if record_irq
+            // actually ran for this IRQ above.
+            if recorded {
+                crate::drivers::gic::stats::IRQ_STATS.lock().exit_irq(crate::drivers::gic::stats::now_us());
+            }
+
             // Return early - handled by user-space
             return;
         }
@@ -456,9 +466,12 @@ pub extern "C" fn rust_irq_handler() {
         
         // End of interrupt
         crate::drivers::gic::write_eoi(iar);
-        
-        // Record exit from interrupt handler
-        crate::drivers::gic::stats::IRQ_STATS.lock().exit_irq();
+
+        // Record exit from interrupt handler, but only if record_irq
+        // actually ran for this IRQ above.
+        if recorded {
+            crate::drivers::gic::stats::IRQ_STATS.lock().exit_irq(crate::drivers::gic::stats::now_us());
+        }
     }
 }
 
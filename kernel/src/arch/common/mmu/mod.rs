@@ -25,6 +25,12 @@ impl MmuFlags {
         Self(self.0 | other.0)
     }
 
+    // Clear the given bits, e.g. downgrading a writable mapping to
+    // read-only after relocations have finished patching it.
+    pub fn without(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
     // 转换为架构特定的标志位
     pub fn to_arch(&self, arch: ArchType) -> u64 {
         match arch {
@@ -111,6 +111,7 @@ impl ConsoleManager {
 
     /// 记录日志
     pub fn log(&self, level: &str, module: &str, args: fmt::Arguments) {
+        logbuf::record(level, module, args);
         self.write(format_args!("[{}] <{}> => {}\n", level, module, args));
     }
 
@@ -202,6 +203,106 @@ pub mod loglvl {
     }
 }
 
+/// Bounded ring buffer of recently emitted log lines.
+///
+/// `ConsoleManager::log` feeds every line here in addition to writing it
+/// to the UART, so a remote management client can pull recent history over
+/// IPC (see `drivers::gic::management`) without needing a serial cable.
+pub mod logbuf {
+    extern crate alloc;
+    use alloc::collections::VecDeque;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use shared::sync::mutex::Mutex;
+
+    /// Oldest records are dropped once the buffer holds this many lines.
+    const CAPACITY: usize = 256;
+
+    /// One captured log line, plus the sequence number a client's cursor
+    /// advances past.
+    #[derive(Clone)]
+    pub struct LogRecord {
+        pub seq: u64,
+        pub level: String,
+        pub module: String,
+        pub message: String,
+    }
+
+    struct LogBuffer {
+        records: VecDeque<LogRecord>,
+        next_seq: u64,
+    }
+
+    impl LogBuffer {
+        const fn new() -> Self {
+            Self { records: VecDeque::new(), next_seq: 0 }
+        }
+    }
+
+    static LOG_BUFFER: Mutex<LogBuffer> = Mutex::new(LogBuffer::new());
+
+    /// Append a log line, evicting the oldest record once `CAPACITY` lines
+    /// are held. Called from the interrupt-time logging path. Each record
+    /// allocates owned copies of its level, module and message strings, and
+    /// the deque grows on demand until it first reaches `CAPACITY`.
+    pub fn record(level: &str, module: &str, args: core::fmt::Arguments) {
+        use alloc::string::ToString;
+        let mut buffer = LOG_BUFFER.lock();
+        if buffer.records.len() == CAPACITY {
+            buffer.records.pop_front();
+        }
+        let seq = buffer.next_seq;
+        buffer.next_seq += 1;
+        buffer.records.push_back(LogRecord {
+            seq,
+            level: level.to_string(),
+            module: module.to_string(),
+            message: args.to_string(),
+        });
+    }
+
+    /// Copy out every record with `seq >= cursor`, and the cursor a caller
+    /// should pass next time to resume from there. The lock is held only
+    /// long enough to clone the matching records, so a slow puller never
+    /// stalls `record` for longer than that copy.
+    pub fn pull_since(cursor: u64) -> (Vec<LogRecord>, u64) {
+        let buffer = LOG_BUFFER.lock();
+        let matching: Vec<LogRecord> = buffer
+            .records
+            .iter()
+            .filter(|r| r.seq >= cursor)
+            .cloned()
+            .collect();
+        let next_cursor = matching.last().map(|r| r.seq + 1).unwrap_or(cursor);
+        (matching, next_cursor)
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::*;
+
+        #[test]
+        fn test_pull_since_advances_cursor() {
+            record("INFO", "test", format_args!("first"));
+            record("WARN", "test", format_args!("second"));
+
+            let (records, cursor) = pull_since(0);
+            assert!(records.len() >= 2);
+            let (empty, same_cursor) = pull_since(cursor);
+            assert!(empty.is_empty());
+            assert_eq!(same_cursor, cursor);
+        }
+
+        #[test]
+        fn test_capacity_is_bounded() {
+            for i in 0..CAPACITY + 10 {
+                record("INFO", "test", format_args!("line {}", i));
+            }
+            assert_eq!(LOG_BUFFER.lock().records.len(), CAPACITY);
+        }
+    }
+}
+
 #[macro_export]
 macro_rules! trace {
     ($($arg:tt)*) => {{
@@ -0,0 +1,1037 @@
+//! Enhanced IPC system with support for synchronous/asynchronous communication,
+//! priority-based messaging, and improved security integration.
+
+extern crate alloc;
+
+use alloc::collections::{BTreeMap, VecDeque};
+use core::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use spin::Mutex;
+
+/// Priority levels for messages
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum Priority {
+    Low = 0,
+    Normal = 1,
+    High = 2,
+    Critical = 3,
+}
+
+/// Enhanced IPC message with priority support
+#[derive(Clone)]
+pub struct IpcMessage {
+    pub msg_id: u64,
+    pub src_pid: u32,
+    pub dst_epid: u32,
+    pub op: u16,
+    pub priority: Priority,
+    pub data_len: usize,
+    pub data: [u8; 256], // Fixed size array instead of Vec
+    pub timestamp: u64,
+    /// When set, the payload lives in a shared region instead of `data`;
+    /// the receiver copies it out by offset/length with `region_read`.
+    /// No borrowed view of the region is handed out.
+    pub region: Option<RegionTransfer>,
+    /// The (protocol, version) negotiated on the destination endpoint via
+    /// `endpoint_negotiate` at send time, if any, so a receiver can tell
+    /// which version of `op`'s semantics the sender is speaking.
+    pub protocol: Option<(ProtocolId, u16)>,
+}
+
+/// Identifies a wire protocol spoken over an endpoint (e.g. a specific
+/// service's request/response schema), distinct from the endpoint itself
+/// so one endpoint can be renegotiated to a different protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ProtocolId(pub u32);
+
+/// Protocol spoken by an endpoint before any negotiation has taken place.
+pub const DEFAULT_PROTOCOL_ID: ProtocolId = ProtocolId(0);
+
+/// Version assumed for `DEFAULT_PROTOCOL_ID` before any negotiation.
+pub const DEFAULT_PROTOCOL_VERSION: u16 = 1;
+
+/// Unique identifier for a shared memory region
+pub type RegionId = u32;
+
+/// A reference to a byte range within a shared region, carried by an
+/// `IpcMessage` in place of inline data for bulk transfers. The bytes are
+/// copied into the region with `region_write` and out of it with
+/// `region_read`; they are never copied into the message itself.
+#[derive(Clone, Copy)]
+pub struct RegionTransfer {
+    pub region: RegionId,
+    pub offset: usize,
+    pub len: usize,
+}
+
+/// Response message
+#[derive(Clone)]
+pub struct IpcResponse {
+    pub msg_id: u64,
+    pub code: i32,
+    pub data_len: usize,
+    pub data: [u8; 256], // Fixed size array instead of Vec
+}
+
+/// Endpoint capabilities for access control
+#[derive(Clone, Copy)]
+pub struct EndpointCapabilities {
+    pub read: bool,
+    pub write: bool,
+    pub admin: bool,
+}
+
+/// Handle for asynchronous operations
+pub struct AsyncHandle {
+    pub id: u64,
+    pub status: AsyncStatus,
+    pub result: Option<IpcResponse>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum AsyncStatus {
+    Pending,
+    Completed,
+    Error,
+    Cancelled,
+}
+
+/// Error types for IPC operations
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IpcError {
+    InvalidEndpoint,
+    PermissionDenied,
+    QueueFull,
+    Timeout,
+    InvalidMessage,
+    OutOfMemory,
+    AlreadyExists,
+    NotFound,
+    InvalidOperation,
+    SystemError,
+    /// The endpoint's bounded queue is full; try again once the receiver
+    /// has drained a slot instead of growing the queue unbounded.
+    WouldBlock,
+    /// `endpoint_negotiate` found no protocol version both sides support.
+    Incompatible,
+}
+
+impl From<IpcError> for i32 {
+    fn from(err: IpcError) -> i32 {
+        match err {
+            IpcError::InvalidEndpoint => -1,
+            IpcError::PermissionDenied => -2,
+            IpcError::QueueFull => -3,
+            IpcError::Timeout => -4,
+            IpcError::InvalidMessage => -5,
+            IpcError::OutOfMemory => -6,
+            IpcError::AlreadyExists => -7,
+            IpcError::NotFound => -8,
+            IpcError::InvalidOperation => -9,
+            IpcError::SystemError => -10,
+            IpcError::WouldBlock => -11,
+            IpcError::Incompatible => -12,
+        }
+    }
+}
+
+/// Endpoint statistics for diagnostics
+#[derive(Default, Clone)]
+pub struct EndpointStats {
+    pub messages_sent: u64,
+    pub messages_received: u64,
+    pub bytes_transferred: u64,
+    pub errors: u64,
+    pub creation_time: u64,
+}
+
+/// Enhanced endpoint with priority queues
+pub struct Endpoint {
+    pub id: u32,
+    pub owner_pid: u32,
+    pub capabilities: EndpointCapabilities,
+
+    // Bounded-queue flow control
+    pub capacity: usize,
+    pub messages_queued: usize,
+
+    // Protocol this endpoint was created to speak, the highest version it
+    // accepts, and the version actually agreed with a peer via
+    // `endpoint_negotiate`, if negotiation has happened yet.
+    pub protocol_id: ProtocolId,
+    pub max_version: u16,
+    pub negotiated: Option<(ProtocolId, u16)>,
+
+    // Per-peer capability overrides, keyed by grantee pid (see
+    // `effective_capabilities`)
+    pub peer_caps: BTreeMap<u32, EndpointCapabilities>,
+
+    // Priority-based message queues
+    pub critical_priority_queue: VecDeque<IpcMessage>,
+    pub high_priority_queue: VecDeque<IpcMessage>,
+    pub normal_priority_queue: VecDeque<IpcMessage>,
+    pub low_priority_queue: VecDeque<IpcMessage>,
+
+    // Wait queues for blocking operations
+    pub waiters: [u32; 16],
+    pub waiters_head: usize,
+    pub waiters_tail: usize,
+    pub waiters_len: usize,
+
+    // Statistics and diagnostics
+    pub stats: EndpointStats,
+}
+
+/// Pending response tracking for synchronous IPC
+struct PendingResponse {
+    msg_id: u64,
+    sender_pid: u32,
+    response: Option<IpcResponse>,
+}
+
+const MAX_PENDING_RESPONSES: usize = 64;
+
+// Global state
+static NEXT_ENDPOINT_ID: AtomicU32 = AtomicU32::new(1);
+static NEXT_MSG_ID: AtomicU64 = AtomicU64::new(1);
+static ENDPOINTS: Mutex<[Option<Endpoint>; 16]> = Mutex::new([const { None }; 16]);
+static PENDING_RESPONSES: Mutex<[Option<PendingResponse>; MAX_PENDING_RESPONSES]> = Mutex::new([const { None }; MAX_PENDING_RESPONSES]);
+
+/// Initialize the IPC system
+pub fn init() {
+    // Initialization is handled by static initialization
+}
+
+/// Default queue capacity for an endpoint, mirroring a typical bounded
+/// mpsc channel size.
+pub const DEFAULT_ENDPOINT_CAPACITY: usize = 64;
+
+/// Helper function to create an empty endpoint
+fn create_empty_endpoint(
+    id: u32,
+    owner_pid: u32,
+    capabilities: EndpointCapabilities,
+    capacity: usize,
+    protocol_id: ProtocolId,
+    max_version: u16,
+) -> Endpoint {
+    Endpoint {
+        id,
+        owner_pid,
+        capabilities,
+        capacity,
+        messages_queued: 0,
+        protocol_id,
+        max_version,
+        negotiated: None,
+        peer_caps: BTreeMap::new(),
+        critical_priority_queue: VecDeque::new(),
+        high_priority_queue: VecDeque::new(),
+        normal_priority_queue: VecDeque::new(),
+        low_priority_queue: VecDeque::new(),
+        waiters: [0; 16],
+        waiters_head: 0,
+        waiters_tail: 0,
+        waiters_len: 0,
+        stats: EndpointStats::default(),
+    }
+}
+
+/// Push a message onto the queue matching its priority
+fn enqueue_by_priority(endpoint: &mut Endpoint, mut msg: IpcMessage) {
+    msg.protocol = endpoint.negotiated;
+    match msg.priority {
+        Priority::Critical => endpoint.critical_priority_queue.push_back(msg),
+        Priority::High => endpoint.high_priority_queue.push_back(msg),
+        Priority::Normal => endpoint.normal_priority_queue.push_back(msg),
+        Priority::Low => endpoint.low_priority_queue.push_back(msg),
+    }
+    endpoint.messages_queued += 1;
+}
+
+/// Pop the next message, preferring higher-priority queues first
+fn dequeue_highest_priority(endpoint: &mut Endpoint) -> Option<IpcMessage> {
+    let msg = endpoint.critical_priority_queue.pop_front()
+        .or_else(|| endpoint.high_priority_queue.pop_front())
+        .or_else(|| endpoint.normal_priority_queue.pop_front())
+        .or_else(|| endpoint.low_priority_queue.pop_front());
+
+    if msg.is_some() {
+        endpoint.messages_queued -= 1;
+    }
+
+    msg
+}
+
+/// Resolve the capabilities a given sender/receiver actually holds against
+/// an endpoint: the owner always has full rights, an explicitly granted
+/// peer entry overrides the endpoint's blanket capabilities, and anyone
+/// else falls back to the endpoint's default (pre-per-peer-grant) rights.
+fn effective_capabilities(endpoint: &Endpoint, pid: u32) -> EndpointCapabilities {
+    if endpoint.owner_pid == pid {
+        return EndpointCapabilities { read: true, write: true, admin: true };
+    }
+    endpoint.peer_caps.get(&pid).copied().unwrap_or(endpoint.capabilities)
+}
+
+/// Create a new endpoint with the specified capabilities and a bounded
+/// message queue capacity. Once `capacity` messages are queued, further
+/// sends are rejected with `IpcError::WouldBlock` until the receiver
+/// drains a slot — this is what keeps a fast producer from exhausting
+/// kernel memory.
+pub fn endpoint_create(
+    capabilities: EndpointCapabilities,
+    capacity: usize,
+    protocol_id: ProtocolId,
+    max_version: u16,
+) -> Result<u32, IpcError> {
+    let epid = NEXT_ENDPOINT_ID.fetch_add(1, Ordering::Relaxed);
+    let owner_pid = super::scheduler::current_pid() as u32;
+
+    let mut endpoints = ENDPOINTS.lock();
+
+    // Find an empty slot
+    for slot in endpoints.iter_mut() {
+        if slot.is_none() {
+            let endpoint = create_empty_endpoint(epid, owner_pid, capabilities, capacity, protocol_id, max_version);
+            *slot = Some(endpoint);
+            return Ok(epid);
+        }
+    }
+
+    Err(IpcError::OutOfMemory)
+}
+
+/// Negotiate a protocol version with the endpoint's peer: picks the
+/// highest version present in `supported` that does not exceed the
+/// endpoint's `max_version`, records it as the endpoint's negotiated
+/// protocol (stamped onto every subsequently enqueued message), and
+/// returns it. Fails with `IpcError::Incompatible` if no offered version
+/// is acceptable.
+pub fn endpoint_negotiate(epid: u32, supported: &[(ProtocolId, u16)]) -> Result<(ProtocolId, u16), IpcError> {
+    let mut endpoints = ENDPOINTS.lock();
+    let endpoint = endpoints
+        .iter_mut()
+        .flatten()
+        .find(|e| e.id == epid)
+        .ok_or(IpcError::InvalidEndpoint)?;
+
+    let best = supported
+        .iter()
+        .filter(|(id, version)| *id == endpoint.protocol_id && *version <= endpoint.max_version)
+        .max_by_key(|(_, version)| *version)
+        .copied();
+
+    match best {
+        Some(agreed) => {
+            endpoint.negotiated = Some(agreed);
+            Ok(agreed)
+        }
+        None => Err(IpcError::Incompatible),
+    }
+}
+
+/// Number of messages currently queued on an endpoint, across all
+/// priority queues.
+pub fn endpoint_queue_len(epid: u32) -> Result<usize, IpcError> {
+    let endpoints = ENDPOINTS.lock();
+
+    for slot in endpoints.iter() {
+        if let Some(ref endpoint) = slot {
+            if endpoint.id == epid {
+                return Ok(endpoint.messages_queued);
+            }
+        }
+    }
+
+    Err(IpcError::InvalidEndpoint)
+}
+
+/// Destroy an endpoint
+pub fn endpoint_destroy(epid: u32) -> Result<(), IpcError> {
+    let current_pid = super::scheduler::current_pid() as u32;
+
+    let mut endpoints = ENDPOINTS.lock();
+
+    for slot in endpoints.iter_mut() {
+        if let Some(ref mut endpoint) = slot {
+            if endpoint.id == epid {
+                if endpoint.owner_pid == current_pid || effective_capabilities(endpoint, current_pid).admin {
+                    region_detach_endpoint(epid);
+                    *slot = None;
+                    return Ok(());
+                } else {
+                    return Err(IpcError::PermissionDenied);
+                }
+            }
+        }
+    }
+
+    Err(IpcError::InvalidEndpoint)
+}
+
+/// Add a pending response entry for synchronous IPC
+fn add_pending_response(msg_id: u64, sender_pid: u32) -> Result<(), IpcError> {
+    let mut pending = PENDING_RESPONSES.lock();
+
+    // Find empty slot
+    for slot in pending.iter_mut() {
+        if slot.is_none() {
+            *slot = Some(PendingResponse {
+                msg_id,
+                sender_pid,
+                response: None,
+            });
+            return Ok(());
+        }
+    }
+
+    Err(IpcError::QueueFull)
+}
+
+/// Set response for a pending message and return sender PID
+fn set_pending_response(msg_id: u64, response: IpcResponse) -> Result<u32, IpcError> {
+    let mut pending = PENDING_RESPONSES.lock();
+
+    for slot in pending.iter_mut() {
+        if let Some(pr) = slot {
+            if pr.msg_id == msg_id && pr.response.is_none() {
+                pr.response = Some(response);
+                return Ok(pr.sender_pid);
+            }
+        }
+    }
+
+    Err(IpcError::NotFound)
+}
+
+/// Get and remove a pending response by message ID
+fn get_and_remove_pending_response(msg_id: u64) -> Option<(u32, IpcResponse)> {
+    let mut pending = PENDING_RESPONSES.lock();
+
+    for slot in pending.iter_mut() {
+        if let Some(pr) = slot {
+            if pr.msg_id == msg_id {
+                if let Some(response) = pr.response.take() {
+                    let sender_pid = pr.sender_pid;
+                    *slot = None; // Remove the entry
+                    return Some((sender_pid, response));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Send a message synchronously
+pub fn endpoint_send_sync(dst_epid: u32, mut msg: IpcMessage) -> Result<IpcResponse, IpcError> {
+    let current_pid = super::scheduler::current_pid() as u32;
+
+    // Generate unique message ID
+    let msg_id = NEXT_MSG_ID.fetch_add(1, Ordering::Relaxed);
+
+    // Add pending response entry before sending message
+    add_pending_response(msg_id, current_pid)?;
+
+    // Set message fields
+    msg.msg_id = msg_id;
+    msg.src_pid = current_pid;
+    msg.dst_epid = dst_epid;
+    msg.timestamp = crate::arch::timer::now();
+
+    // Get destination endpoint
+    let mut endpoints = ENDPOINTS.lock();
+
+    for slot in endpoints.iter_mut() {
+        if let Some(ref mut endpoint) = slot {
+            if endpoint.id == dst_epid {
+                // Check write permission
+                if !effective_capabilities(endpoint, current_pid).write {
+                    let _ = get_and_remove_pending_response(msg_id);
+                    return Err(IpcError::PermissionDenied);
+                }
+
+                // Reject once the bounded queue is full, rather than
+                // letting a fast producer exhaust kernel memory.
+                if endpoint.messages_queued >= endpoint.capacity {
+                    let _ = get_and_remove_pending_response(msg_id);
+                    return Err(IpcError::WouldBlock);
+                }
+
+                // Increment sent counter
+                endpoint.stats.messages_sent += 1;
+                endpoint.stats.bytes_transferred += msg.data_len as u64;
+
+                // Add to appropriate priority queue
+                enqueue_by_priority(endpoint, msg);
+
+                // Wake up any waiting processes
+                if endpoint.waiters_len > 0 {
+                    let pid = endpoint.waiters[endpoint.waiters_head];
+                    endpoint.waiters_head = (endpoint.waiters_head + 1) % endpoint.waiters.len();
+                    endpoint.waiters_len -= 1;
+                    let _ = crate::process::wake_process(pid as usize);
+                }
+
+                // Release endpoint lock before waiting for response
+                drop(endpoints); // Explicitly drop the lock
+
+                // Wait for response with timeout using process blocking
+                const TIMEOUT_TICKS: u64 = 1000; // Adjust based on desired timeout
+                if !crate::process::block_process_timeout(current_pid as usize, TIMEOUT_TICKS) {
+                    // Failed to block, clean up pending response
+                    let _ = get_and_remove_pending_response(msg_id);
+                    return Err(IpcError::SystemError);
+                }
+                // Process will be woken up either by response or timeout
+                // When resumed, check if response is available
+                if let Some((_sender_pid, response)) = get_and_remove_pending_response(msg_id) {
+                    return Ok(response);
+                } else {
+                    return Err(IpcError::Timeout);
+                }
+            }
+        }
+    }
+
+    // Clean up pending response if endpoint not found
+    let _ = get_and_remove_pending_response(msg_id);
+    Err(IpcError::InvalidEndpoint)
+}
+
+/// Receive a message synchronously
+pub fn endpoint_recv_sync(epid: u32, _timeout_ms: Option<u64>) -> Result<IpcMessage, IpcError> {
+    let current_pid = super::scheduler::current_pid() as u32;
+
+    // Get endpoint
+    let mut endpoints = ENDPOINTS.lock();
+
+    for slot in endpoints.iter_mut() {
+        if let Some(ref mut endpoint) = slot {
+            if endpoint.id == epid {
+                // Check read permission
+                if !effective_capabilities(endpoint, current_pid).read {
+                    return Err(IpcError::PermissionDenied);
+                }
+
+                // Try to get message from the highest priority non-empty queue
+                if let Some(msg) = dequeue_highest_priority(endpoint) {
+                    endpoint.stats.messages_received += 1;
+                    endpoint.stats.bytes_transferred += msg.data_len as u64;
+                    return Ok(msg);
+                }
+
+                // No messages available, add to waiters queue and block the process
+                if endpoint.waiters_len < endpoint.waiters.len() {
+                    endpoint.waiters[endpoint.waiters_tail] = current_pid;
+                    endpoint.waiters_tail = (endpoint.waiters_tail + 1) % endpoint.waiters.len();
+                    endpoint.waiters_len += 1;
+
+                    // Drop the lock before blocking
+                    drop(endpoints);
+                    let _ = crate::process::block_process(current_pid as usize);
+
+                    // When woken up, try again (simplified - in reality would need to check again)
+                    return Err(IpcError::Timeout);
+                } else {
+                    return Err(IpcError::QueueFull);
+                }
+            }
+        }
+    }
+
+    Err(IpcError::InvalidEndpoint)
+}
+
+/// Send a message without blocking the caller, returning a handle that can
+/// be polled with [`async_wait`] for the response once the receiver replies
+/// via `endpoint_send_response`. Unlike `endpoint_send_sync`, this never
+/// parks the calling process: a full queue or a not-yet-arrived response are
+/// both reported by returning immediately, not by blocking.
+pub fn endpoint_send_async(dst_epid: u32, mut msg: IpcMessage) -> Result<AsyncHandle, IpcError> {
+    let current_pid = super::scheduler::current_pid() as u32;
+    let msg_id = NEXT_MSG_ID.fetch_add(1, Ordering::Relaxed);
+    add_pending_response(msg_id, current_pid)?;
+
+    msg.msg_id = msg_id;
+    msg.src_pid = current_pid;
+    msg.dst_epid = dst_epid;
+    msg.timestamp = crate::arch::timer::now();
+
+    let mut endpoints = ENDPOINTS.lock();
+
+    for slot in endpoints.iter_mut() {
+        if let Some(ref mut endpoint) = slot {
+            if endpoint.id == dst_epid {
+                if !effective_capabilities(endpoint, current_pid).write {
+                    let _ = get_and_remove_pending_response(msg_id);
+                    return Err(IpcError::PermissionDenied);
+                }
+
+                if endpoint.messages_queued >= endpoint.capacity {
+                    let _ = get_and_remove_pending_response(msg_id);
+                    return Err(IpcError::WouldBlock);
+                }
+
+                endpoint.stats.messages_sent += 1;
+                endpoint.stats.bytes_transferred += msg.data_len as u64;
+                enqueue_by_priority(endpoint, msg);
+
+                if endpoint.waiters_len > 0 {
+                    let pid = endpoint.waiters[endpoint.waiters_head];
+                    endpoint.waiters_head = (endpoint.waiters_head + 1) % endpoint.waiters.len();
+                    endpoint.waiters_len -= 1;
+                    let _ = crate::process::wake_process(pid as usize);
+                }
+
+                return Ok(AsyncHandle { id: msg_id, status: AsyncStatus::Pending, result: None });
+            }
+        }
+    }
+
+    let _ = get_and_remove_pending_response(msg_id);
+    Err(IpcError::InvalidEndpoint)
+}
+
+/// Attempt to enqueue a message without blocking or waiting for a
+/// response. Returns `IpcError::WouldBlock` immediately if the endpoint's
+/// queue is full instead of parking the caller.
+pub fn try_send(dst_epid: u32, mut msg: IpcMessage) -> Result<(), IpcError> {
+    let current_pid = super::scheduler::current_pid() as u32;
+    let mut endpoints = ENDPOINTS.lock();
+
+    for slot in endpoints.iter_mut() {
+        if let Some(ref mut endpoint) = slot {
+            if endpoint.id == dst_epid {
+                if !effective_capabilities(endpoint, current_pid).write {
+                    return Err(IpcError::PermissionDenied);
+                }
+
+                if endpoint.messages_queued >= endpoint.capacity {
+                    return Err(IpcError::WouldBlock);
+                }
+
+                msg.msg_id = NEXT_MSG_ID.fetch_add(1, Ordering::Relaxed);
+                msg.src_pid = current_pid;
+                msg.dst_epid = dst_epid;
+                msg.timestamp = crate::arch::timer::now();
+
+                endpoint.stats.messages_sent += 1;
+                endpoint.stats.bytes_transferred += msg.data_len as u64;
+                enqueue_by_priority(endpoint, msg);
+
+                if endpoint.waiters_len > 0 {
+                    let pid = endpoint.waiters[endpoint.waiters_head];
+                    endpoint.waiters_head = (endpoint.waiters_head + 1) % endpoint.waiters.len();
+                    endpoint.waiters_len -= 1;
+                    let _ = crate::process::wake_process(pid as usize);
+                }
+
+                return Ok(());
+            }
+        }
+    }
+
+    Err(IpcError::InvalidEndpoint)
+}
+
+/// Send a batch of messages in one endpoint lock acquisition, stopping
+/// early once the bounded queue fills rather than rejecting the whole
+/// batch. Useful for a producer that has accumulated several messages and
+/// wants to avoid re-acquiring the endpoint lock per message. Returns the
+/// number of messages actually enqueued.
+///
+/// When `allow_empty` is `false`, zero-length, op-0 messages are treated
+/// as no-op control messages and silently dropped from the batch instead
+/// of occupying a queue slot.
+pub fn endpoint_send_batch(dst_epid: u32, msgs: &[IpcMessage], allow_empty: bool) -> Result<usize, IpcError> {
+    let current_pid = super::scheduler::current_pid() as u32;
+    let mut endpoints = ENDPOINTS.lock();
+
+    for slot in endpoints.iter_mut() {
+        if let Some(ref mut endpoint) = slot {
+            if endpoint.id == dst_epid {
+                if !effective_capabilities(endpoint, current_pid).write {
+                    return Err(IpcError::PermissionDenied);
+                }
+
+                let mut accepted = 0usize;
+                for msg in msgs {
+                    if !allow_empty && msg.data_len == 0 && msg.op == 0 {
+                        continue;
+                    }
+                    if endpoint.messages_queued >= endpoint.capacity {
+                        break;
+                    }
+
+                    let mut msg = msg.clone();
+                    msg.msg_id = NEXT_MSG_ID.fetch_add(1, Ordering::Relaxed);
+                    msg.src_pid = current_pid;
+                    msg.dst_epid = dst_epid;
+                    msg.timestamp = crate::arch::timer::now();
+
+                    endpoint.stats.messages_sent += 1;
+                    endpoint.stats.bytes_transferred += msg.data_len as u64;
+                    enqueue_by_priority(endpoint, msg);
+                    accepted += 1;
+                }
+
+                if accepted > 0 {
+                    if endpoint.waiters_len > 0 {
+                        let pid = endpoint.waiters[endpoint.waiters_head];
+                        endpoint.waiters_head = (endpoint.waiters_head + 1) % endpoint.waiters.len();
+                        endpoint.waiters_len -= 1;
+                        let _ = crate::process::wake_process(pid as usize);
+                    }
+                }
+
+                return Ok(accepted);
+            }
+        }
+    }
+
+    Err(IpcError::InvalidEndpoint)
+}
+
+/// Poll an [`AsyncHandle`] returned by [`endpoint_send_async`] for its
+/// response. Never blocks the calling process: if the receiver hasn't
+/// called `endpoint_send_response` yet, returns `Err(IpcError::WouldBlock)`
+/// so the caller can retry later (e.g. on its next scheduler turn) instead
+/// of parking.
+pub fn async_wait(handle: AsyncHandle, _timeout_ms: Option<u64>) -> Result<IpcResponse, IpcError> {
+    match handle.status {
+        AsyncStatus::Completed => handle.result.ok_or(IpcError::SystemError),
+        AsyncStatus::Error => Err(IpcError::SystemError),
+        AsyncStatus::Cancelled => Err(IpcError::InvalidOperation),
+        AsyncStatus::Pending => match get_and_remove_pending_response(handle.id) {
+            Some((_sender_pid, response)) => Ok(response),
+            None => Err(IpcError::WouldBlock),
+        },
+    }
+}
+
+/// Cancel an asynchronous send, dropping its pending-response slot so a
+/// late reply from the receiver is discarded instead of leaking a slot.
+pub fn async_cancel(handle: AsyncHandle) -> Result<(), IpcError> {
+    let mut pending = PENDING_RESPONSES.lock();
+    for slot in pending.iter_mut() {
+        if slot.as_ref().map_or(false, |pr| pr.msg_id == handle.id) {
+            *slot = None;
+        }
+    }
+    Ok(())
+}
+
+/// Send a response to a synchronous IPC message
+///
+/// This function is called by the receiver (service) to send a response
+/// back to the original sender. The msg_id should come from the received
+/// IpcMessage.
+pub fn endpoint_send_response(msg_id: u64, code: i32, data: &[u8]) -> Result<(), IpcError> {
+    // Create response
+    let mut response_data = [0u8; 256];
+    let data_len = data.len().min(256);
+    response_data[..data_len].copy_from_slice(&data[..data_len]);
+
+    let response = IpcResponse {
+        msg_id,
+        code,
+        data_len,
+        data: response_data,
+    };
+
+    // Set the pending response
+    let sender_pid = set_pending_response(msg_id, response)?;
+    // Wake up the sender process
+    let _ = crate::process::wake_process(sender_pid as usize);
+    Ok(())
+}
+
+/// Grant capabilities to a specific process on an endpoint. The grant is
+/// keyed by the grantee's pid, so different peers can hold different
+/// rights on the same endpoint (see `effective_capabilities`).
+pub fn endpoint_grant_capability(epid: u32, pid: u32, cap: EndpointCapabilities) -> Result<(), IpcError> {
+    let current_pid = super::scheduler::current_pid() as u32;
+
+    let mut endpoints = ENDPOINTS.lock();
+
+    for slot in endpoints.iter_mut() {
+        if let Some(ref mut endpoint) = slot {
+            if endpoint.id == epid {
+                // Only the owner or an existing admin holder can grant capabilities
+                if endpoint.owner_pid == current_pid || effective_capabilities(endpoint, current_pid).admin {
+                    endpoint.peer_caps.insert(pid, cap);
+                    return Ok(());
+                } else {
+                    return Err(IpcError::PermissionDenied);
+                }
+            }
+        }
+    }
+
+    Err(IpcError::InvalidEndpoint)
+}
+
+/// Revoke a previously granted per-process capability, returning the
+/// affected process to the endpoint's default (blanket) rights.
+pub fn endpoint_revoke_capability(epid: u32, pid: u32) -> Result<(), IpcError> {
+    let current_pid = super::scheduler::current_pid() as u32;
+
+    let mut endpoints = ENDPOINTS.lock();
+
+    for slot in endpoints.iter_mut() {
+        if let Some(ref mut endpoint) = slot {
+            if endpoint.id == epid {
+                // Only the owner or an existing admin holder can revoke capabilities
+                if endpoint.owner_pid == current_pid || effective_capabilities(endpoint, current_pid).admin {
+                    return match endpoint.peer_caps.remove(&pid) {
+                        Some(_) => Ok(()),
+                        None => Err(IpcError::NotFound),
+                    };
+                } else {
+                    return Err(IpcError::PermissionDenied);
+                }
+            }
+        }
+    }
+
+    Err(IpcError::InvalidEndpoint)
+}
+
+/// Get endpoint statistics
+pub fn get_endpoint_stats(epid: u32) -> Result<EndpointStats, IpcError> {
+    let endpoints = ENDPOINTS.lock();
+
+    for slot in endpoints.iter() {
+        if let Some(ref endpoint) = slot {
+            if endpoint.id == epid {
+                return Ok(endpoint.stats.clone());
+            }
+        }
+    }
+
+    Err(IpcError::InvalidEndpoint)
+}
+
+/// Check if an endpoint with the given ID exists
+pub fn endpoint_exists(epid: u32) -> bool {
+    let endpoints = ENDPOINTS.lock();
+    endpoints.iter().any(|slot| {
+        slot.as_ref().map_or(false, |endpoint| endpoint.id == epid)
+    })
+}
+
+/// A shared memory region for zero-copy bulk IPC transfers. Rather than
+/// copying a large payload into `IpcMessage::data`, a sender hands off a
+/// `RegionTransfer` referencing an offset/length inside one of these, and
+/// the receiver reads (or writes) the bytes directly out of the region.
+struct Region {
+    owner_pid: u32,
+    data: alloc::vec::Vec<u8>,
+    // epid -> capabilities granted to that endpoint over this region
+    attachments: BTreeMap<u32, EndpointCapabilities>,
+}
+
+static NEXT_REGION_ID: AtomicU32 = AtomicU32::new(1);
+static REGIONS: Mutex<BTreeMap<RegionId, Region>> = Mutex::new(BTreeMap::new());
+
+/// Create a new shared memory region of `size` bytes.
+pub fn region_create(size: usize) -> Result<RegionId, IpcError> {
+    let owner_pid = super::scheduler::current_pid() as u32;
+    let id = NEXT_REGION_ID.fetch_add(1, Ordering::Relaxed);
+
+    let region = Region {
+        owner_pid,
+        data: alloc::vec![0u8; size],
+        attachments: BTreeMap::new(),
+    };
+    REGIONS.lock().insert(id, region);
+    Ok(id)
+}
+
+/// Grant an endpoint `caps` access to a region, so `IpcMessage`s it
+/// receives can reference the region's bytes by offset/length instead of
+/// copying them inline. Only the region's owner may attach it.
+pub fn region_attach(epid: u32, region: RegionId, caps: EndpointCapabilities) -> Result<(), IpcError> {
+    let current_pid = super::scheduler::current_pid() as u32;
+
+    if !endpoint_exists(epid) {
+        return Err(IpcError::InvalidEndpoint);
+    }
+
+    let mut regions = REGIONS.lock();
+    let r = regions.get_mut(&region).ok_or(IpcError::NotFound)?;
+    if r.owner_pid != current_pid {
+        return Err(IpcError::PermissionDenied);
+    }
+
+    r.attachments.insert(epid, caps);
+    Ok(())
+}
+
+/// Release every attachment an endpoint holds across all regions. Called
+/// when the endpoint is destroyed, so later `region_read`/`region_write`
+/// calls naming that endpoint id are rejected.
+fn region_detach_endpoint(epid: u32) {
+    let mut regions = REGIONS.lock();
+    for region in regions.values_mut() {
+        region.attachments.remove(&epid);
+    }
+}
+
+/// Copy the bytes referenced by `transfer` out of its region into `buf`,
+/// validating that `epid` is attached to the region with read rights and
+/// that the offset/length fall within the region's bounds.
+pub fn region_read(epid: u32, transfer: &RegionTransfer, buf: &mut [u8]) -> Result<usize, IpcError> {
+    let regions = REGIONS.lock();
+    let region = regions.get(&transfer.region).ok_or(IpcError::NotFound)?;
+
+    let caps = region.attachments.get(&epid).ok_or(IpcError::PermissionDenied)?;
+    if !caps.read {
+        return Err(IpcError::PermissionDenied);
+    }
+
+    let end = transfer.offset.checked_add(transfer.len).ok_or(IpcError::InvalidMessage)?;
+    if end > region.data.len() {
+        return Err(IpcError::InvalidMessage);
+    }
+
+    let n = buf.len().min(transfer.len);
+    buf[..n].copy_from_slice(&region.data[transfer.offset..transfer.offset + n]);
+    Ok(n)
+}
+
+/// Copy `data` into the bytes referenced by `transfer` within its region,
+/// validating that `epid` is attached to the region with write rights and
+/// that the offset/length fall within the region's bounds.
+pub fn region_write(epid: u32, transfer: &RegionTransfer, data: &[u8]) -> Result<usize, IpcError> {
+    let mut regions = REGIONS.lock();
+    let region = regions.get_mut(&transfer.region).ok_or(IpcError::NotFound)?;
+
+    let caps = region.attachments.get(&epid).ok_or(IpcError::PermissionDenied)?;
+    if !caps.write {
+        return Err(IpcError::PermissionDenied);
+    }
+
+    let end = transfer.offset.checked_add(transfer.len).ok_or(IpcError::InvalidMessage)?;
+    if end > region.data.len() {
+        return Err(IpcError::InvalidMessage);
+    }
+
+    let n = data.len().min(transfer.len);
+    region.data[transfer.offset..transfer.offset + n].copy_from_slice(&data[..n]);
+    Ok(n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capabilities_grant_read_write() {
+        let mut endpoint = create_empty_endpoint(
+            1, 0,
+            EndpointCapabilities { read: true, write: true, admin: true },
+            DEFAULT_ENDPOINT_CAPACITY,
+            DEFAULT_PROTOCOL_ID,
+            DEFAULT_PROTOCOL_VERSION,
+        );
+
+        endpoint.peer_caps.insert(7, EndpointCapabilities { read: true, write: true, admin: false });
+
+        let caps = effective_capabilities(&endpoint, 7);
+        assert!(caps.read);
+        assert!(caps.write);
+    }
+
+    #[test]
+    fn test_read_only_peer_rejected_for_write_op() {
+        let mut endpoint = create_empty_endpoint(
+            1, 0,
+            EndpointCapabilities { read: true, write: true, admin: true },
+            DEFAULT_ENDPOINT_CAPACITY,
+            DEFAULT_PROTOCOL_ID,
+            DEFAULT_PROTOCOL_VERSION,
+        );
+
+        // Grant a peer read-only rights, as `endpoint_grant_capability` would.
+        endpoint.peer_caps.insert(7, EndpointCapabilities { read: true, write: false, admin: false });
+
+        let caps = effective_capabilities(&endpoint, 7);
+        assert!(caps.read);
+        assert!(!caps.write, "a read-only grant must reject write-class ops");
+    }
+
+    #[test]
+    fn test_revoke_capability_restores_default_rights() {
+        let mut endpoint = create_empty_endpoint(
+            1, 0,
+            EndpointCapabilities { read: true, write: false, admin: true },
+            DEFAULT_ENDPOINT_CAPACITY,
+            DEFAULT_PROTOCOL_ID,
+            DEFAULT_PROTOCOL_VERSION,
+        );
+
+        endpoint.peer_caps.insert(7, EndpointCapabilities { read: true, write: true, admin: false });
+        assert!(effective_capabilities(&endpoint, 7).write);
+
+        // Revoking drops the override; the peer falls back to the
+        // endpoint's default (blanket) capabilities.
+        endpoint.peer_caps.remove(&7);
+        assert!(!effective_capabilities(&endpoint, 7).write);
+    }
+
+    #[test]
+    fn test_region_round_trip_and_bounds_check() {
+        let region = region_create(64).expect("region_create");
+        let epid = endpoint_create(
+            EndpointCapabilities { read: true, write: true, admin: true },
+            DEFAULT_ENDPOINT_CAPACITY,
+            DEFAULT_PROTOCOL_ID,
+            DEFAULT_PROTOCOL_VERSION,
+        ).expect("endpoint_create");
+
+        region_attach(epid, region, EndpointCapabilities { read: true, write: true, admin: false })
+            .expect("region_attach");
+
+        let transfer = RegionTransfer { region, offset: 4, len: 8 };
+        region_write(epid, &transfer, b"zerocopy").expect("region_write");
+
+        let mut buf = [0u8; 8];
+        let n = region_read(epid, &transfer, &mut buf).expect("region_read");
+        assert_eq!(n, 8);
+        assert_eq!(&buf, b"zerocopy");
+
+        // Out-of-bounds offset/len must be rejected, not silently clamped.
+        let oob = RegionTransfer { region, offset: 60, len: 16 };
+        assert_eq!(region_write(epid, &oob, b"overflow"), Err(IpcError::InvalidMessage));
+    }
+
+    #[test]
+    fn test_negotiate_picks_highest_mutual_version() {
+        let epid = endpoint_create(
+            EndpointCapabilities { read: true, write: true, admin: true },
+            DEFAULT_ENDPOINT_CAPACITY,
+            ProtocolId(1),
+            3,
+        ).expect("endpoint_create");
+
+        let agreed = endpoint_negotiate(epid, &[(ProtocolId(1), 1), (ProtocolId(1), 2), (ProtocolId(1), 5)])
+            .expect("endpoint_negotiate");
+        assert_eq!(agreed, (ProtocolId(1), 2), "must not exceed the endpoint's max_version");
+    }
+
+    #[test]
+    fn test_negotiate_rejects_incompatible_protocol() {
+        let epid = endpoint_create(
+            EndpointCapabilities { read: true, write: true, admin: true },
+            DEFAULT_ENDPOINT_CAPACITY,
+            ProtocolId(1),
+            3,
+        ).expect("endpoint_create");
+
+        let result = endpoint_negotiate(epid, &[(ProtocolId(2), 1)]);
+        assert_eq!(result, Err(IpcError::Incompatible));
+    }
+}
@@ -0,0 +1,8 @@
+//! Core kernel subsystems
+//!
+//! This module contains the fundamental building blocks of the HNX microkernel:
+//! - **IPC**: Inter-Process Communication with priority-based messaging
+//! - **Scheduler**: Task scheduling and context switching
+
+pub mod ipc;
+pub mod scheduler;
@@ -261,8 +261,10 @@ impl DeviceManager {
                 data_len: 12, // Only the first 12 bytes are meaningful
                 data,
                 timestamp: get_timestamp(),
+                region: None,
+                protocol: None,
             };
-            
+
             if let Err(e) = endpoint_send_sync(epid.0 as u32, msg) {
                 crate::error!("Failed to forward IRQ {} to driver: {:?}", irq, e);
             } else {
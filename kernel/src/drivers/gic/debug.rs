@@ -52,22 +52,54 @@ pub fn dump_irq_info(irq: u32) {
 /// Dump all active interrupts and their priorities
 pub fn dump_active_irqs() {
     crate::info!("=== Active Interrupts ===");
-    
+
     let stats = IRQ_STATS.lock();
     let active_irqs = stats.get_active_irqs();
-    
+
     if active_irqs.is_empty() {
         crate::info!("No interrupts have occurred yet");
         return;
     }
-    
-    crate::info!("IRQ | Priority | Name         | Count");
-    crate::info!("----+----------+--------------+-------");
-    
+
+    crate::info!("IRQ | Priority | Name         | Count   | Ewma(us) | Max(us)");
+    crate::info!("----+----------+--------------+---------+----------+--------");
+
     for (irq, count) in active_irqs {
         let priority = super::get_interrupt_priority(irq);
-        crate::info!("{:3} | {:8} | {:12} | {}", 
-            irq, priority, priority_name(priority), count);
+        let (ewma_us, max_us) = stats
+            .get_latency(irq)
+            .map(|l| (l.ewma_us, l.max_us))
+            .unwrap_or((0, 0));
+        crate::info!("{:3} | {:8} | {:12} | {:7} | {:8} | {}",
+            irq, priority, priority_name(priority), count, ewma_us, max_us);
+    }
+}
+
+/// Dump the full entry-to-EOI service time report for a single IRQ:
+/// sample count, min/max, the exponentially-weighted moving average, and
+/// the log-scale histogram. Useful for spotting an IRQ with pathological
+/// worst-case handling time even when its average (and `dump_active_irqs`'s
+/// count column) look unremarkable.
+pub fn dump_irq_latency(irq: u32) {
+    let stats = IRQ_STATS.lock();
+    let Some(latency) = stats.get_latency(irq) else {
+        crate::info!("IRQ {} is out of range", irq);
+        return;
+    };
+
+    crate::info!("=== IRQ {} Latency ===", irq);
+    if latency.count == 0 {
+        crate::info!("No samples recorded yet");
+        return;
+    }
+
+    crate::info!("Samples: {}", latency.count);
+    crate::info!("Min: {}us  Ewma: {}us  Max: {}us", latency.min_us, latency.ewma_us, latency.max_us);
+    crate::info!("Histogram (bucket = bit-length of the microsecond duration):");
+    for (bucket, count) in latency.histogram.iter().enumerate() {
+        if *count > 0 {
+            crate::info!("  {:2}-bit: {}", bucket, count);
+        }
     }
 }
 
@@ -1,6 +1,7 @@
 pub mod v2;
 pub mod stats;
 pub mod debug;
+pub mod management;
 
 use crate::arch::common::traits::InterruptController;
 
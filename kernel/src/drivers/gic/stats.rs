@@ -7,9 +7,70 @@ use spin::Mutex;
 extern crate alloc;
 use alloc::vec::Vec;
 
+use crate::arch::aarch64::AArch64;
+use crate::arch::common::traits::Timer;
+
 /// Maximum number of interrupt IDs to track (GICv2 supports up to 1020)
 const MAX_IRQS: usize = 1020;
 
+/// Shift `k` in `ewma = ewma + (sample - ewma) >> k`; higher means slower
+/// to react to new samples but less jittery.
+const EWMA_SHIFT: u32 = 3;
+
+/// Number of log-scale latency buckets. Bucket `i` holds samples whose
+/// microsecond duration has a `i`-bit value (so bucket 0 is 0us, bucket 1
+/// is 1us, bucket 2 is [2,4)us, bucket 3 is [4,8)us, ...). The last bucket
+/// also collects every longer sample, i.e. everything from 2^14us (about
+/// 16ms) up.
+const LATENCY_BUCKETS: usize = 16;
+
+/// Current time in microseconds, used to time interrupt service latency.
+pub fn now_us() -> u64 {
+    AArch64::timer_now() / 1000
+}
+
+fn latency_bucket(duration_us: u64) -> usize {
+    let bits = 64 - duration_us.leading_zeros() as usize;
+    core::cmp::min(bits, LATENCY_BUCKETS - 1)
+}
+
+/// Entry-to-EOI service time statistics for a single IRQ.
+#[derive(Debug, Clone, Copy)]
+pub struct IrqLatency {
+    pub count: u64,
+    pub min_us: u64,
+    pub max_us: u64,
+    /// Exponentially-weighted moving average, see `EWMA_SHIFT`.
+    pub ewma_us: u64,
+    /// Log-scale histogram, see `LATENCY_BUCKETS`.
+    pub histogram: [u32; LATENCY_BUCKETS],
+}
+
+impl IrqLatency {
+    const fn new() -> Self {
+        Self {
+            count: 0,
+            min_us: u64::MAX,
+            max_us: 0,
+            ewma_us: 0,
+            histogram: [0; LATENCY_BUCKETS],
+        }
+    }
+
+    fn record(&mut self, duration_us: u64) {
+        self.count += 1;
+        if duration_us < self.min_us {
+            self.min_us = duration_us;
+        }
+        if duration_us > self.max_us {
+            self.max_us = duration_us;
+        }
+        let diff = duration_us as i64 - self.ewma_us as i64;
+        self.ewma_us = (self.ewma_us as i64 + (diff >> EWMA_SHIFT)) as u64;
+        self.histogram[latency_bucket(duration_us)] += 1;
+    }
+}
+
 /// Interrupt statistics collector
 pub struct InterruptStats {
     /// Per-IRQ interrupt counts
@@ -22,6 +83,11 @@ pub struct InterruptStats {
     pub current_nesting: usize,
     /// Total number of interrupts processed
     pub total_irqs: u64,
+    /// Per-IRQ entry-to-EOI service time tracking
+    latency: [IrqLatency; MAX_IRQS],
+    /// Entry timestamps (microseconds) for currently-active IRQs, in the
+    /// order they were entered; nesting unwinds LIFO, same as EOI.
+    active: Vec<(u32, u64)>,
 }
 
 impl InterruptStats {
@@ -33,6 +99,8 @@ impl InterruptStats {
             max_nesting: 0,
             current_nesting: 0,
             total_irqs: 0,
+            latency: [IrqLatency::new(); MAX_IRQS],
+            active: Vec::new(),
         }
     }
 
@@ -41,7 +109,9 @@ impl InterruptStats {
     /// # Arguments
     /// * `irq` - The interrupt ID that occurred
     /// * `was_preemption` - Whether this interrupt preempted another handler
-    pub fn record_irq(&mut self, irq: u32, was_preemption: bool) {
+    /// * `now_us` - Entry timestamp (microseconds), matched up with the
+    ///   corresponding `exit_irq` call to measure service time
+    pub fn record_irq(&mut self, irq: u32, was_preemption: bool, now_us: u64) {
         // Update per-IRQ count
         if (irq as usize) < MAX_IRQS {
             self.counts[irq as usize] += 1;
@@ -60,13 +130,30 @@ impl InterruptStats {
 
         // Total count
         self.total_irqs += 1;
+
+        self.active.push((irq, now_us));
     }
 
     /// Record exit from interrupt handler
-    pub fn exit_irq(&mut self) {
+    ///
+    /// Must only be called once for each `record_irq` call it is paired
+    /// with — if a caller skips `record_irq` (e.g. because the nesting
+    /// depth limit was hit) it must skip the matching `exit_irq` too,
+    /// otherwise this pops the `active` entry and nesting count belonging
+    /// to a different, genuinely-nested IRQ.
+    ///
+    /// # Arguments
+    /// * `now_us` - EOI timestamp (microseconds), used with the matching
+    ///   `record_irq` entry timestamp to update that IRQ's latency stats
+    pub fn exit_irq(&mut self, now_us: u64) {
         if self.current_nesting > 0 {
             self.current_nesting -= 1;
         }
+        if let Some((irq, start_us)) = self.active.pop() {
+            if (irq as usize) < MAX_IRQS {
+                self.latency[irq as usize].record(now_us.saturating_sub(start_us));
+            }
+        }
     }
 
     /// Get interrupt count for a specific IRQ
@@ -78,6 +165,15 @@ impl InterruptStats {
         }
     }
 
+    /// Get latency statistics for a specific IRQ
+    pub fn get_latency(&self, irq: u32) -> Option<IrqLatency> {
+        if (irq as usize) < MAX_IRQS {
+            Some(self.latency[irq as usize])
+        } else {
+            None
+        }
+    }
+
     /// Get all active IRQs (IRQs with non-zero counts)
     pub fn get_active_irqs(&self) -> Vec<(u32, u64)> {
         let mut active = Vec::new();
@@ -96,6 +192,8 @@ impl InterruptStats {
         self.max_nesting = 0;
         self.current_nesting = 0;
         self.total_irqs = 0;
+        self.latency = [IrqLatency::new(); MAX_IRQS];
+        self.active.clear();
     }
 }
 
@@ -127,16 +225,16 @@ mod test {
     #[test]
     fn test_record_irq() {
         let mut stats = InterruptStats::new();
-        
+
         // Record normal interrupt
-        stats.record_irq(30, false);
+        stats.record_irq(30, false, 0);
         assert_eq!(stats.get_count(30), 1);
         assert_eq!(stats.total_irqs, 1);
         assert_eq!(stats.preemptions, 0);
         assert_eq!(stats.current_nesting, 1);
-        
+
         // Record preemption
-        stats.record_irq(31, true);
+        stats.record_irq(31, true, 0);
         assert_eq!(stats.get_count(31), 1);
         assert_eq!(stats.total_irqs, 2);
         assert_eq!(stats.preemptions, 1);
@@ -147,29 +245,80 @@ mod test {
     #[test]
     fn test_exit_irq() {
         let mut stats = InterruptStats::new();
-        
-        stats.record_irq(30, false);
+
+        stats.record_irq(30, false, 0);
         assert_eq!(stats.current_nesting, 1);
-        
-        stats.exit_irq();
+
+        stats.exit_irq(0);
         assert_eq!(stats.current_nesting, 0);
-        
+
         // Should not go negative
-        stats.exit_irq();
+        stats.exit_irq(0);
         assert_eq!(stats.current_nesting, 0);
     }
 
     #[test]
     fn test_reset() {
         let mut stats = InterruptStats::new();
-        
-        stats.record_irq(30, false);
-        stats.record_irq(31, true);
-        
+
+        stats.record_irq(30, false, 0);
+        stats.record_irq(31, true, 0);
+
         stats.reset();
         assert_eq!(stats.get_count(30), 0);
         assert_eq!(stats.get_count(31), 0);
         assert_eq!(stats.total_irqs, 0);
         assert_eq!(stats.preemptions, 0);
     }
+
+    #[test]
+    fn test_latency_tracking() {
+        let mut stats = InterruptStats::new();
+
+        stats.record_irq(30, false, 100);
+        stats.exit_irq(150); // 50us service time
+        stats.record_irq(30, false, 200);
+        stats.exit_irq(210); // 10us service time
+
+        let latency = stats.get_latency(30).expect("IRQ 30 in range");
+        assert_eq!(latency.count, 2);
+        assert_eq!(latency.min_us, 10);
+        assert_eq!(latency.max_us, 50);
+        // ewma should have moved from 0 towards the samples, but not reach them
+        assert!(latency.ewma_us > 0 && latency.ewma_us < 50);
+    }
+
+    #[test]
+    fn test_skipped_record_does_not_corrupt_other_active_irqs() {
+        let mut stats = InterruptStats::new();
+
+        // IRQ 30 genuinely nests.
+        stats.record_irq(30, false, 0);
+        // IRQ 31 hits the nesting depth limit: the caller must skip
+        // record_irq *and* the matching exit_irq, leaving IRQ 30's
+        // entry alone.
+        assert_eq!(stats.current_nesting, 1);
+
+        // IRQ 30 exits; its latency and nesting must be exactly as if
+        // IRQ 31 had never been attempted.
+        stats.exit_irq(50);
+        assert_eq!(stats.current_nesting, 0);
+        assert_eq!(stats.get_latency(30).unwrap().max_us, 50);
+    }
+
+    #[test]
+    fn test_latency_nesting_is_lifo() {
+        let mut stats = InterruptStats::new();
+
+        // Outer IRQ 30 starts at t=0, is preempted by IRQ 31 at t=10,
+        // which finishes at t=30 (20us), then the outer finishes at t=50
+        // (50us total, including the time it was preempted).
+        stats.record_irq(30, false, 0);
+        stats.record_irq(31, true, 10);
+        stats.exit_irq(30);
+        stats.exit_irq(50);
+
+        assert_eq!(stats.get_latency(31).unwrap().max_us, 20);
+        assert_eq!(stats.get_latency(30).unwrap().max_us, 50);
+    }
 }
\ No newline at end of file
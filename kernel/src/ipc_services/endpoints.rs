@@ -24,6 +24,9 @@ pub enum ServiceEndpoint {
     Audio = 7,
     /// Input device service (keyboard, mouse, etc.)
     Input = 8,
+    /// Remote management channel (IRQ stats, kernel log pull) — see
+    /// `drivers::gic::management`.
+    Management = 9,
 }
 
 impl ServiceEndpoint {
@@ -43,6 +46,7 @@ impl ServiceEndpoint {
             ServiceEndpoint::Display => "display",
             ServiceEndpoint::Audio => "audio",
             ServiceEndpoint::Input => "input",
+            ServiceEndpoint::Management => "management",
         }
     }
     
@@ -57,6 +61,7 @@ impl ServiceEndpoint {
             6 => Some(ServiceEndpoint::Display),
             7 => Some(ServiceEndpoint::Audio),
             8 => Some(ServiceEndpoint::Input),
+            9 => Some(ServiceEndpoint::Management),
             _ => None,
         }
     }
@@ -70,6 +75,7 @@ impl WellKnownServices {
     pub const NETWORK_EPID: u32 = ServiceEndpoint::Network.as_u32();
     pub const LOADER_EPID: u32 = ServiceEndpoint::Loader.as_u32();
     pub const BLOCK_EPID: u32 = ServiceEndpoint::BlockDevice.as_u32();
+    pub const MANAGEMENT_EPID: u32 = ServiceEndpoint::Management.as_u32();
 }
 
 /// Global instance for convenience
@@ -80,7 +86,10 @@ pub const WELL_KNOWN_SERVICES: WellKnownServices = WellKnownServices;
 /// This is called during kernel boot to create the well-known endpoints
 /// that user space services will use to receive requests.
 pub fn init_service_endpoints() -> Result<(), ()> {
-    use crate::core::ipc::{endpoint_create, EndpointCapabilities};
+    use crate::core::ipc::{
+        endpoint_create, EndpointCapabilities, DEFAULT_ENDPOINT_CAPACITY, DEFAULT_PROTOCOL_ID,
+        DEFAULT_PROTOCOL_VERSION,
+    };
     
     crate::info!("ipc_services: creating well-known service endpoints");
     
@@ -98,10 +107,17 @@ pub fn init_service_endpoints() -> Result<(), ()> {
         ServiceEndpoint::Network,
         ServiceEndpoint::Loader,
         ServiceEndpoint::BlockDevice,
+        // Endpoint ids are handed out sequentially, so every service up to
+        // Management (id 9) is created to keep the ids in step.
+        ServiceEndpoint::CharDevice,
+        ServiceEndpoint::Display,
+        ServiceEndpoint::Audio,
+        ServiceEndpoint::Input,
+        ServiceEndpoint::Management,
     ];
-    
+
     for service in &services {
-        match endpoint_create(caps) {
+        match endpoint_create(caps, DEFAULT_ENDPOINT_CAPACITY, DEFAULT_PROTOCOL_ID, DEFAULT_PROTOCOL_VERSION) {
             Ok(epid) => {
                 if epid == service.as_u32() {
                     crate::info!("ipc_services: created endpoint {} for {}", epid, service.name());
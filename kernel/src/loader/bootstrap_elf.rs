@@ -0,0 +1,646 @@
+//! Minimal ELF loader - ONLY for bootstrapping init process
+//!
+//! This is a HARDCODED, MINIMAL ELF loader that ONLY loads the init binary.
+//! It does NOT handle:
+//! - Complex segments
+//! - TLS
+//! - Multi-threading
+//!
+//! Statically-linked-PIE / ET_DYN binaries are resolved in-kernel via their
+//! `PT_DYNAMIC` segment below; everything else should still go through the
+//! user space Loader Service.
+
+use alloc::vec::Vec;
+
+use crate::arch::common::mmu::MmuFlags;
+use crate::memory::virtual_::{
+    create_user_l1, ensure_page_resident, map_in_pt, query_mapping_in_pt, remap_in_pt,
+    vma_add_file, FileBacking,
+};
+use crate::{info, error};
+
+const ELF_MAGIC: &[u8] = &[0x7F, b'E', b'L', b'F'];
+const ELF_CLASS_64: u8 = 2;
+const ELF_DATA_LSB: u8 = 1;
+const ET_EXEC: u16 = 2;
+const ET_DYN: u16 = 3;
+const EM_AARCH64: u16 = 183;
+const PT_LOAD: u32 = 1;
+const PT_DYNAMIC: u32 = 2;
+const PT_GNU_RELRO: u32 = 0x6474_e552;
+
+/// Size of one on-disk `Elf64_Phdr` entry (p_type, p_flags, p_offset,
+/// p_vaddr, p_paddr, p_filesz, p_memsz, p_align).
+const ELF64_PHDR_SIZE: usize = 56;
+
+// Dynamic section tags (subset) used to resolve PT_DYNAMIC relocations.
+const DT_NULL: u64 = 0;
+const DT_PLTRELSZ: u64 = 2;
+const DT_STRTAB: u64 = 5;
+const DT_SYMTAB: u64 = 6;
+const DT_RELA: u64 = 7;
+const DT_RELASZ: u64 = 8;
+const DT_RELAENT: u64 = 9;
+const DT_JMPREL: u64 = 23;
+
+// AArch64 dynamic relocation types (subset)
+const R_AARCH64_ABS64: u32 = 257;
+const R_AARCH64_GLOB_DAT: u32 = 1025;
+const R_AARCH64_JUMP_SLOT: u32 = 1026;
+const R_AARCH64_RELATIVE: u32 = 1027;
+
+// ELF auxiliary vector tags (subset) used to build the initial stack image.
+const AT_NULL: u64 = 0;
+const AT_PHDR: u64 = 3;
+const AT_PHENT: u64 = 4;
+const AT_PHNUM: u64 = 5;
+const AT_PAGESZ: u64 = 6;
+const AT_ENTRY: u64 = 9;
+const AT_HWCAP: u64 = 16;
+const AT_RANDOM: u64 = 25;
+
+// Baseline AArch64 HWCAP bits (FP + ASIMD), matching what QEMU's `elfload.c`
+// reports for a minimal virt machine.
+const HWCAP_FP: u64 = 1 << 0;
+const HWCAP_ASIMD: u64 = 1 << 1;
+
+const STACK_TOP: usize = 0x8000_0000;
+const STACK_PAGES: usize = 8;
+
+struct Elf64Sym {
+    st_name: u32,
+    st_info: u8,
+    st_other: u8,
+    st_shndx: u16,
+    st_value: u64,
+    st_size: u64,
+}
+
+struct Elf64Rela {
+    r_offset: u64,
+    r_info: u64,
+    r_addend: i64,
+}
+
+/// One `PT_DYNAMIC` entry; `d_val` doubles as `d_ptr` per the ELF spec union.
+struct Elf64Dyn {
+    d_tag: u64,
+    d_val: u64,
+}
+
+/// Tables pulled out of a `PT_DYNAMIC` segment's `Elf64Dyn` array. Addresses
+/// here are still link-time virtual addresses; callers translate them to
+/// file offsets with `vaddr_to_file_offset` before reading.
+struct DynamicInfo {
+    /// (vaddr, size, entsize) from DT_RELA/DT_RELASZ/DT_RELAENT
+    rela: Option<(usize, usize, usize)>,
+    /// (vaddr, size) from DT_JMPREL/DT_PLTRELSZ
+    jmprel: Option<(usize, usize)>,
+    /// vaddr from DT_SYMTAB
+    symtab: usize,
+}
+
+/// Clean (write back) `size` bytes starting at `addr` from the data cache, so
+/// a relocation patched through a direct physical-address write is visible
+/// to instruction fetch / DMA without waiting for natural eviction.
+fn clean_dcache_range(addr: usize, size: usize) {
+    const CACHE_LINE: usize = 64;
+    let mut a = addr & !(CACHE_LINE - 1);
+    let end = addr + size;
+    unsafe {
+        while a < end {
+            core::arch::asm!("dc cvac, {0}", in(reg) a);
+            a += CACHE_LINE;
+        }
+        core::arch::asm!("dsb sy");
+    }
+}
+
+/// Minimal ELF loader - only for init process
+pub struct BootstrapElfLoader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> BootstrapElfLoader<'a> {
+    pub fn new(data: &'a [u8]) -> Result<Self, &'static str> {
+        if data.len() < 64 {
+            return Err("ELF file too small for an ELF64 header");
+        }
+
+        if &data[0..4] != ELF_MAGIC {
+            return Err("Invalid ELF magic");
+        }
+
+        if data[4] != ELF_CLASS_64 {
+            return Err("Not 64-bit ELF");
+        }
+
+        if data[5] != ELF_DATA_LSB {
+            return Err("Not little-endian ELF");
+        }
+
+        let this = Self { data };
+
+        let e_type = this.read_u16(16);
+        if e_type != ET_EXEC && e_type != ET_DYN {
+            return Err("Unsupported ELF type (expected ET_EXEC or ET_DYN)");
+        }
+
+        let e_machine = this.read_u16(18);
+        if e_machine != EM_AARCH64 {
+            return Err("Unsupported ELF machine (expected AArch64)");
+        }
+
+        let phentsize = this.read_u16(54) as usize;
+        let phnum = this.read_u16(56) as usize;
+        if phentsize < ELF64_PHDR_SIZE {
+            return Err("ELF program header entry too small");
+        }
+        let phoff = this.read_u64(32) as usize;
+        let ph_table_size = phentsize.checked_mul(phnum).ok_or("ELF program header table size overflows")?;
+        let ph_table_end = phoff.checked_add(ph_table_size).ok_or("ELF program header table end overflows")?;
+        if ph_table_end > this.data.len() {
+            return Err("ELF program header table extends past end of file");
+        }
+
+        Ok(this)
+    }
+
+    /// Load init ELF and return (entry, sp, pt_base). `argv0` becomes the
+    /// sole entry of `argv`; the process is started with an empty `envp`.
+    pub fn load_init(&self, argv0: &str) -> Result<(usize, usize, usize), &'static str> {
+        let e_type = self.read_u16(16);
+        let phoff = self.read_u64(32) as usize;
+        let phentsize = self.read_u16(54) as usize;
+        let phnum = self.read_u16(56) as usize;
+
+        // ET_DYN binaries (statically-linked PIE) carry link-time addresses
+        // starting at 0, so a random bias can be added freely to place
+        // successive runs at different addresses (basic ASLR). ET_EXEC
+        // binaries already carry real, fixed link addresses and must load
+        // at bias 0.
+        let bias: usize = if e_type == ET_DYN {
+            const ASLR_BASE: usize = 0x1000_0000;
+            const ASLR_SLOTS: usize = 0x400;
+            let seed = crate::arch::timer::now() as usize;
+            ASLR_BASE + (seed % ASLR_SLOTS) * 0x1000
+        } else {
+            0
+        };
+        let entry = self.read_u64(24) as usize + bias;
+
+        info!("bootstrap: ELF entry=0x{:X}, {} program headers, bias=0x{:X}", entry, phnum, bias);
+        info!("bootstrap: phoff=0x{:X}, phentsize={}, phnum={}", phoff, phentsize, phnum);
+
+        let pt_base = create_user_l1().ok_or("Failed to create page table")?;
+
+        let mut dyn_info: Option<DynamicInfo> = None;
+
+        for i in 0..phnum {
+            let ph_offset = phoff + i * phentsize;
+            let p_type = self.read_u32_at(ph_offset);
+
+            if p_type == PT_DYNAMIC {
+                dyn_info = self.parse_dynamic_section(ph_offset);
+                continue;
+            }
+            if p_type != PT_LOAD {
+                continue;
+            }
+
+            let p_flags = self.read_u32_at(ph_offset + 4);
+            let p_offset = self.read_u64_at(ph_offset + 8) as usize;
+            let p_vaddr = self.read_u64_at(ph_offset + 16) as usize + bias;
+            let p_filesz = self.read_u64_at(ph_offset + 32) as usize;
+            let p_memsz = self.read_u64_at(ph_offset + 40) as usize;
+
+            let r = if p_flags & 0x4 != 0 { 'R' } else { '-' };
+            let w = if p_flags & 0x2 != 0 { 'W' } else { '-' };
+            let x = if p_flags & 0x1 != 0 { 'X' } else { '-' };
+
+            info!("bootstrap: LOAD segment[{}] vaddr=0x{:X}, offset=0x{:X}, filesz=0x{:X}, memsz=0x{:X}, flags={}{}{}",
+                  i, p_vaddr, p_offset, p_filesz, p_memsz, r, w, x);
+
+            self.load_segment(pt_base, p_vaddr, p_offset, p_filesz, p_memsz, p_flags)?;
+        }
+
+        if let Some(info) = dyn_info {
+            let applied = self.apply_dynamic_relocations(&info, bias, pt_base);
+            info!("bootstrap: dynamic relocations applied: {}", applied);
+        }
+
+        self.apply_gnu_relro(phoff, phentsize, phnum, bias, pt_base);
+
+        let stack_base = STACK_TOP - STACK_PAGES * 0x1000;
+        let mut stack_page_map: [(usize, usize); STACK_PAGES] = [(0, 0); STACK_PAGES];
+        for (i, slot) in stack_page_map.iter_mut().enumerate() {
+            let stack_va = stack_base + i * 0x1000;
+            let stack_pa = crate::memory::physical::alloc_pages(1).ok_or("Failed to allocate stack")?;
+            let stack_flags = MmuFlags::READ
+                .combine(MmuFlags::WRITE)
+                .combine(MmuFlags::USER);
+            map_in_pt(pt_base, stack_va, stack_pa, stack_flags);
+            unsafe {
+                core::ptr::write_bytes(stack_pa as *mut u8, 0, 0x1000);
+            }
+            *slot = (stack_va, stack_pa);
+        }
+
+        let phdr_vaddr = self.file_offset_to_vaddr(phoff, phentsize, phnum, phoff)
+            .map(|v| v + bias)
+            .unwrap_or(bias + phoff);
+        let user_sp = self.build_initial_stack(
+            &stack_page_map, argv0.as_bytes(), phdr_vaddr, phentsize, phnum, entry,
+        )?;
+
+        info!("bootstrap: init loaded - entry=0x{:X}, sp=0x{:X}, pt=0x{:X}",
+              entry, user_sp, pt_base);
+
+        Ok((entry, user_sp, pt_base))
+    }
+
+    /// Build the System V AArch64 initial stack image: a string blob
+    /// (argv strings, then `AT_RANDOM` bytes) at the top of the stack,
+    /// followed by the argv/envp pointer arrays and the auxiliary vector
+    /// just below it. `envp` is always empty for bootstrapped processes.
+    /// Returns the resulting stack pointer, pointing at `argc`.
+    fn build_initial_stack(
+        &self,
+        stack_page_map: &[(usize, usize); STACK_PAGES],
+        argv0: &[u8],
+        phdr_vaddr: usize,
+        phentsize: usize,
+        phnum: usize,
+        entry: usize,
+    ) -> Result<usize, &'static str> {
+        let mut random_bytes = [0u8; 16];
+        let seed = crate::arch::timer::now();
+        for (i, b) in random_bytes.iter_mut().enumerate() {
+            *b = seed.wrapping_mul(0x9E37_79B9_7F4A_7C15).wrapping_add(i as u64) as u8;
+        }
+
+        let mut string_blob: Vec<u8> = Vec::new();
+        let argv_offset = string_blob.len();
+        string_blob.extend_from_slice(argv0);
+        string_blob.push(0);
+        let random_offset = string_blob.len();
+        string_blob.extend_from_slice(&random_bytes);
+
+        let auxv: [(u64, u64); 7] = [
+            (AT_PHDR, phdr_vaddr as u64),
+            (AT_PHENT, phentsize as u64),
+            (AT_PHNUM, phnum as u64),
+            (AT_PAGESZ, 0x1000),
+            (AT_ENTRY, entry as u64),
+            (AT_HWCAP, HWCAP_FP | HWCAP_ASIMD),
+            (AT_RANDOM, 0), // patched in below once the string blob's base VA is known
+        ];
+
+        // argc, argv[0], NULL, envp NULL, auxv pairs, AT_NULL pair
+        let ptr_area_words = 1 + 2 + 1 + (auxv.len() + 1) * 2;
+        let ptr_area_size = ptr_area_words * 8;
+        let total_size = ptr_area_size + string_blob.len();
+        let total_rounded = (total_size + 15) & !15usize;
+        if total_rounded > STACK_PAGES * 0x1000 {
+            return Err("argv/envp/auxv too large for the mapped init stack");
+        }
+
+        let sp = STACK_TOP - total_rounded;
+        let string_base_va = sp + ptr_area_size;
+
+        Self::write_stack_bytes(stack_page_map, string_base_va, &string_blob);
+
+        let mut ptr_area: Vec<u8> = Vec::with_capacity(ptr_area_size);
+        ptr_area.extend_from_slice(&1u64.to_le_bytes()); // argc
+        ptr_area.extend_from_slice(&((string_base_va + argv_offset) as u64).to_le_bytes());
+        ptr_area.extend_from_slice(&0u64.to_le_bytes()); // argv NULL terminator
+        ptr_area.extend_from_slice(&0u64.to_le_bytes()); // envp NULL terminator (empty envp)
+        for &(tag, val) in &auxv {
+            let val = if tag == AT_RANDOM { (string_base_va + random_offset) as u64 } else { val };
+            ptr_area.extend_from_slice(&tag.to_le_bytes());
+            ptr_area.extend_from_slice(&val.to_le_bytes());
+        }
+        ptr_area.extend_from_slice(&AT_NULL.to_le_bytes());
+        ptr_area.extend_from_slice(&0u64.to_le_bytes());
+
+        Self::write_stack_bytes(stack_page_map, sp, &ptr_area);
+
+        Ok(sp)
+    }
+
+    /// Find the physical page backing a mapped stack virtual address.
+    fn lookup_stack_page(stack_page_map: &[(usize, usize)], va_page: usize) -> Option<usize> {
+        stack_page_map.iter().find(|&&(va, _)| va == va_page).map(|&(_, pa)| pa)
+    }
+
+    /// Copy `data` into the eagerly-mapped stack pages starting at user VA
+    /// `dest_va`, splitting across page boundaries. Each page is written
+    /// directly at its physical address, found through `stack_page_map`.
+    fn write_stack_bytes(stack_page_map: &[(usize, usize)], dest_va: usize, data: &[u8]) {
+        let mut remaining = data;
+        let mut va = dest_va;
+        while !remaining.is_empty() {
+            let page_va = va & !0xFFFusize;
+            let page_off = va & 0xFFFusize;
+            let pa = match Self::lookup_stack_page(stack_page_map, page_va) {
+                Some(p) => p,
+                None => return,
+            };
+            let to_copy = core::cmp::min(0x1000 - page_off, remaining.len());
+            unsafe {
+                core::ptr::copy_nonoverlapping(remaining.as_ptr(), (pa + page_off) as *mut u8, to_copy);
+            }
+            clean_dcache_range(pa, 0x1000);
+            va += to_copy;
+            remaining = &remaining[to_copy..];
+        }
+    }
+
+    /// Translate a file offset into a link-time virtual address by finding
+    /// the `PT_LOAD` segment that covers it; the inverse of
+    /// `vaddr_to_file_offset`. Used to compute `AT_PHDR` from `e_phoff`.
+    fn file_offset_to_vaddr(&self, phoff: usize, phentsize: usize, phnum: usize, file_off: usize) -> Option<usize> {
+        for i in 0..phnum {
+            let off = phoff + i * phentsize;
+            if self.read_u32_at(off) == PT_LOAD {
+                let p_offset = self.read_u64_at(off + 8) as usize;
+                let p_vaddr = self.read_u64_at(off + 16) as usize;
+                let p_filesz = self.read_u64_at(off + 32) as usize;
+                if file_off >= p_offset && file_off < p_offset + p_filesz {
+                    return Some(p_vaddr + (file_off - p_offset));
+                }
+            }
+        }
+        None
+    }
+
+    /// Register a `PT_LOAD` segment as a demand-paged VMA instead of
+    /// eagerly allocating and copying in every one of its pages: the
+    /// segment's file content is copied into a `FileBacking` once, and
+    /// `virtual_::handle_page_fault` maps and fills each page the first
+    /// time it's actually touched.
+    fn load_segment(
+        &self,
+        pt_base: usize,
+        vaddr: usize,
+        offset: usize,
+        filesz: usize,
+        memsz: usize,
+        flags: u32,
+    ) -> Result<(), &'static str> {
+        let page_start = vaddr & !0xFFF;
+        let page_end = (vaddr + memsz + 0xFFF) & !0xFFF;
+
+        let mut mmu_flags = MmuFlags::USER;
+        if flags & 0x4 != 0 { mmu_flags = mmu_flags.combine(MmuFlags::READ); }
+        if flags & 0x2 != 0 { mmu_flags = mmu_flags.combine(MmuFlags::WRITE); }
+        if flags & 0x1 != 0 { mmu_flags = mmu_flags.combine(MmuFlags::EXECUTE); }
+
+        // W^X: a segment that's both writable and executable would let init
+        // write its own code and jump to it. No legitimate PT_LOAD needs
+        // both, so drop EXECUTE rather than trust the file to ask for a
+        // sane combination.
+        if mmu_flags.contains(MmuFlags::WRITE) && mmu_flags.contains(MmuFlags::EXECUTE) {
+            error!("bootstrap: segment at vaddr=0x{:X} requested W+X, stripping EXECUTE", vaddr);
+            mmu_flags = mmu_flags.without(MmuFlags::EXECUTE);
+        }
+
+        info!("bootstrap: registering segment VMA: page_start=0x{:X}, page_end=0x{:X}, flags={:?}",
+              page_start, page_end, mmu_flags);
+
+        let file_end = offset.checked_add(filesz).ok_or("LOAD segment filesz overflows")?;
+        if file_end > self.data.len() {
+            return Err("LOAD segment extends past end of file");
+        }
+
+        let file_backing = FileBacking {
+            data: Vec::from(&self.data[offset..file_end]),
+            seg_vaddr: vaddr,
+            filesz,
+            memsz,
+        };
+
+        vma_add_file(pt_base, page_start, page_end - page_start, mmu_flags, file_backing);
+
+        Ok(())
+    }
+
+    /// Harden permissions now that every relocation pass has finished
+    /// writing: `PT_GNU_RELRO` covers the GOT and other once-written data
+    /// the dynamic linker populates at load time, and mirrors userspace
+    /// `mprotect` calls to make that range read-only so it can't be
+    /// retargeted later.
+    fn apply_gnu_relro(&self, phoff: usize, phentsize: usize, phnum: usize, bias: usize, pt_base: usize) {
+        for i in 0..phnum {
+            let off = phoff + i * phentsize;
+            if self.read_u32_at(off) != PT_GNU_RELRO {
+                continue;
+            }
+            let p_vaddr = self.read_u64_at(off + 16) as usize + bias;
+            let p_memsz = self.read_u64_at(off + 40) as usize;
+            let seg_start = p_vaddr & !0xFFF;
+            let seg_end = (p_vaddr + p_memsz + 0xFFF) & !0xFFF;
+
+            let mut va = seg_start;
+            while va < seg_end {
+                if ensure_page_resident(pt_base, va).is_some() {
+                    if let Some((_, cur_flags)) = query_mapping_in_pt(pt_base, va) {
+                        if cur_flags.contains(MmuFlags::WRITE) {
+                            remap_in_pt(pt_base, va, cur_flags.without(MmuFlags::WRITE));
+                        }
+                    }
+                }
+                va += 0x1000;
+            }
+
+            info!("bootstrap: GNU_RELRO applied: vaddr=0x{:X}, memsz=0x{:X}", p_vaddr, p_memsz);
+        }
+    }
+
+    /// Walk a `PT_DYNAMIC` segment's `Elf64Dyn` array, collecting the tags
+    /// needed to apply `DT_RELA`/`DT_JMPREL` relocations.
+    fn parse_dynamic_section(&self, ph_offset: usize) -> Option<DynamicInfo> {
+        let p_offset = self.read_u64_at(ph_offset + 8) as usize;
+        let p_filesz = self.read_u64_at(ph_offset + 32) as usize;
+
+        let mut rela_vaddr = 0usize;
+        let mut rela_size = 0usize;
+        let mut rela_ent = core::mem::size_of::<Elf64Rela>();
+        let mut jmprel_vaddr = 0usize;
+        let mut pltrelsz = 0usize;
+        let mut symtab_vaddr = 0usize;
+
+        let dend = core::cmp::min(p_offset.saturating_add(p_filesz), self.data.len());
+        let mut doff = p_offset;
+        while doff + core::mem::size_of::<Elf64Dyn>() <= dend {
+            let d = Elf64Dyn { d_tag: self.read_u64_at(doff), d_val: self.read_u64_at(doff + 8) };
+            match d.d_tag {
+                DT_NULL => break,
+                DT_RELA => rela_vaddr = d.d_val as usize,
+                DT_RELASZ => rela_size = d.d_val as usize,
+                DT_RELAENT => rela_ent = d.d_val as usize,
+                DT_JMPREL => jmprel_vaddr = d.d_val as usize,
+                DT_PLTRELSZ => pltrelsz = d.d_val as usize,
+                DT_SYMTAB => symtab_vaddr = d.d_val as usize,
+                DT_STRTAB => { /* no imported symbol names to resolve yet */ }
+                _ => {}
+            }
+            doff += core::mem::size_of::<Elf64Dyn>();
+        }
+
+        Some(DynamicInfo {
+            rela: if rela_vaddr != 0 && rela_size != 0 { Some((rela_vaddr, rela_size, rela_ent)) } else { None },
+            jmprel: if jmprel_vaddr != 0 && pltrelsz != 0 { Some((jmprel_vaddr, pltrelsz)) } else { None },
+            symtab: symtab_vaddr,
+        })
+    }
+
+    /// Translate a link-time virtual address into a file offset by finding
+    /// the `PT_LOAD` segment that covers it.
+    fn vaddr_to_file_offset(&self, phoff: usize, phentsize: usize, phnum: usize, vaddr: usize) -> Option<usize> {
+        for i in 0..phnum {
+            let off = phoff + i * phentsize;
+            if self.read_u32_at(off) == PT_LOAD {
+                let p_offset = self.read_u64_at(off + 8) as usize;
+                let p_vaddr = self.read_u64_at(off + 16) as usize;
+                let p_filesz = self.read_u64_at(off + 32) as usize;
+                if vaddr >= p_vaddr && vaddr < p_vaddr + p_filesz {
+                    return Some(p_offset + (vaddr - p_vaddr));
+                }
+            }
+        }
+        None
+    }
+
+    /// Apply one `Elf64Rela` entry with load bias `bias`, writing through
+    /// the physical page backing `r_offset` in `pt_base`'s address space
+    /// (faulted in on demand if the segment hasn't been touched yet).
+    fn apply_dyn_rela(
+        &self, bias: usize, symtab_off: Option<usize>, rela: &Elf64Rela, pt_base: usize,
+    ) -> bool {
+        let rtype = (rela.r_info & 0xFFFF_FFFF) as u32;
+        let sym_idx = (rela.r_info >> 32) as usize;
+        let place_va = rela.r_offset as usize + bias;
+        let va_page = place_va & !0xFFF;
+        let pa_page = match ensure_page_resident(pt_base, va_page) {
+            Some(p) => p,
+            None => return false,
+        };
+        let value: u64 = match rtype {
+            R_AARCH64_RELATIVE => (bias as i64 + rela.r_addend) as u64,
+            R_AARCH64_ABS64 | R_AARCH64_GLOB_DAT | R_AARCH64_JUMP_SLOT => {
+                let symtab_off = match symtab_off {
+                    Some(s) => s,
+                    None => return false,
+                };
+                let sym_off = symtab_off + sym_idx * core::mem::size_of::<Elf64Sym>();
+                if sym_off + core::mem::size_of::<Elf64Sym>() > self.data.len() {
+                    return false;
+                }
+                let sym = Elf64Sym {
+                    st_name: self.read_u32_at(sym_off),
+                    st_info: self.data[sym_off + 4],
+                    st_other: self.data[sym_off + 5],
+                    st_shndx: self.read_u16(sym_off + 6),
+                    st_value: self.read_u64_at(sym_off + 8),
+                    st_size: self.read_u64_at(sym_off + 16),
+                };
+                (bias as i64 + sym.st_value as i64 + rela.r_addend) as u64
+            }
+            _ => return false,
+        };
+        let off = place_va & 0xFFF;
+        unsafe {
+            core::ptr::write_unaligned((pa_page + off) as *mut u64, value);
+        }
+        clean_dcache_range(pa_page, 0x1000);
+        true
+    }
+
+    /// Walk a RELA-shaped table (the main RELA table or JMPREL/PLT
+    /// relocations, which share the `Elf64Rela` layout) and apply every
+    /// entry, returning how many were applied.
+    fn apply_relocation_table(
+        &self, bias: usize, symtab_off: Option<usize>,
+        table_vaddr: usize, table_size: usize, entsize: usize,
+        phoff: usize, phentsize: usize, phnum: usize, pt_base: usize,
+    ) -> usize {
+        if entsize == 0 {
+            return 0;
+        }
+        let file_off = match self.vaddr_to_file_offset(phoff, phentsize, phnum, table_vaddr) {
+            Some(o) => o,
+            None => return 0,
+        };
+        let count = table_size / entsize;
+        let mut applied = 0usize;
+        for k in 0..count {
+            let roff = file_off + k * entsize;
+            if roff + core::mem::size_of::<Elf64Rela>() > self.data.len() {
+                break;
+            }
+            let rela = Elf64Rela {
+                r_offset: self.read_u64_at(roff),
+                r_info: self.read_u64_at(roff + 8),
+                r_addend: self.read_u64_at(roff + 16) as i64,
+            };
+            if self.apply_dyn_rela(bias, symtab_off, &rela, pt_base) {
+                applied += 1;
+            }
+        }
+        applied
+    }
+
+    /// Resolve a dynamically-linked (`ET_DYN` / statically-linked-PIE)
+    /// binary's `PT_DYNAMIC` relocations in-kernel.
+    fn apply_dynamic_relocations(&self, info: &DynamicInfo, bias: usize, pt_base: usize) -> usize {
+        let phoff = self.read_u64(32) as usize;
+        let phentsize = self.read_u16(54) as usize;
+        let phnum = self.read_u16(56) as usize;
+        let symtab_off = self.vaddr_to_file_offset(phoff, phentsize, phnum, info.symtab);
+        let mut applied = 0usize;
+        if let Some((rela_vaddr, rela_size, rela_ent)) = info.rela {
+            applied += self.apply_relocation_table(
+                bias, symtab_off, rela_vaddr, rela_size, rela_ent,
+                phoff, phentsize, phnum, pt_base,
+            );
+        }
+        if let Some((jmprel_vaddr, pltrelsz)) = info.jmprel {
+            applied += self.apply_relocation_table(
+                bias, symtab_off, jmprel_vaddr, pltrelsz, core::mem::size_of::<Elf64Rela>(),
+                phoff, phentsize, phnum, pt_base,
+            );
+        }
+        applied
+    }
+
+    fn read_u16(&self, offset: usize) -> u16 {
+        u16::from_le_bytes([self.data[offset], self.data[offset + 1]])
+    }
+
+    fn read_u32_at(&self, offset: usize) -> u32 {
+        u32::from_le_bytes([
+            self.data[offset],
+            self.data[offset + 1],
+            self.data[offset + 2],
+            self.data[offset + 3],
+        ])
+    }
+
+    fn read_u64(&self, offset: usize) -> u64 {
+        self.read_u64_at(offset)
+    }
+
+    fn read_u64_at(&self, offset: usize) -> u64 {
+        u64::from_le_bytes([
+            self.data[offset],
+            self.data[offset + 1],
+            self.data[offset + 2],
+            self.data[offset + 3],
+            self.data[offset + 4],
+            self.data[offset + 5],
+            self.data[offset + 6],
+            self.data[offset + 7],
+        ])
+    }
+}
@@ -132,7 +132,7 @@ pub fn bootstrap_init_process() -> Result<(usize, usize, usize), ()> {
             crate::error!("loader: invalid init ELF: {}", e);
         })?;
 
-    loader.load_init().map_err(|e| {
+    loader.load_init("init").map_err(|e| {
         crate::error!("loader: failed to load init: {}", e);
     })
 }
@@ -165,7 +165,7 @@ pub fn spawn_service_from_initrd(path: &str) -> Result<(usize, usize, usize), ()
             crate::error!("loader: invalid service ELF '{}': {}", path, e);
         })?;
 
-    loader.load_init().map_err(|e| {
+    loader.load_init(path).map_err(|e| {
         crate::error!("loader: failed to load service '{}': {}", path, e);
     })
 }
@@ -134,14 +134,28 @@ fn init_phase3_processes() {
 /// - Begins round-robin scheduling
 fn init_phase4_scheduler() -> ! {
     println!("Kernel core ready");
-    
-    println!("User space is not yet implemented until complete the kernel.");
 
+    println!("User space is not yet implemented until complete the kernel.");
 
+    // No user process is runnable yet, but the management endpoint
+    // (drivers::gic::management) is already registered by
+    // ipc_services::delegate::init(), and nothing was ever driving it -
+    // it would sit there forever with requests piling up unanswered.
+    // Until a real scheduler loop exists, service it from here on every
+    // wakeup. A request is only taken when one is already queued, so an
+    // empty queue never parks this loop in endpoint_recv_sync's waiter
+    // list and never replaces the idle wait.
+    let management_epid = crate::ipc_services::endpoints::WellKnownServices::MANAGEMENT_EPID;
     loop {
+        if crate::core::ipc::endpoint_queue_len(management_epid).unwrap_or(0) > 0 {
+            match crate::drivers::gic::management::service_one_request() {
+                Ok(()) | Err(crate::core::ipc::IpcError::Timeout) => {}
+                Err(e) => crate::warn!("management: service_one_request failed: {:?}", e),
+            }
+        }
         crate::arch::cpu::wait_for_interrupt();
     }
-    
+
     // println!("Attempting to bootstrap init process...");
     // match loader::bootstrap_init_process() {
     //     Ok((entry, sp, pt_base)) => {
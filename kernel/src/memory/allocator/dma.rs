@@ -9,10 +9,32 @@ extern crate alloc;
 use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
 use crate::drivers::ipc_protocol::DriverError;
-use crate::memory::physical::alloc_pages;
+use crate::memory::physical::{alloc_pages, free_pages};
 use crate::security::capability::Capability;
 use shared::sync::mutex::Mutex;
 
+const PAGE_SIZE: usize = 4096;
+
+/// Physical range a DMA buffer is allowed to land in. Most devices can DMA
+/// anywhere, but virtio-style devices with 32-bit-only addressing need their
+/// buffers reachable without a bounce buffer, hence `Below4G`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DmaZone {
+    Any,
+    Below4G,
+}
+
+impl DmaZone {
+    /// Whether a `size`-byte region starting at `physical_address` lies
+    /// entirely inside this zone.
+    fn fits(&self, physical_address: u64, size: usize) -> bool {
+        match self {
+            DmaZone::Any => true,
+            DmaZone::Below4G => physical_address.saturating_add(size as u64) <= 0x1_0000_0000,
+        }
+    }
+}
+
 /// Information about a DMA region
 #[derive(Debug, Clone)]
 pub struct DmaRegion {
@@ -25,6 +47,8 @@ pub struct DmaRegion {
 /// DMA allocator
 pub struct DmaAllocator {
     allocated_regions: BTreeMap<u64, DmaRegion>,
+    /// Freed regions kept sorted by `physical_address` so deallocation can
+    /// find adjacent neighbours to coalesce with.
     free_regions: Vec<DmaRegion>,
 }
 
@@ -44,53 +68,165 @@ impl DmaAllocator {
         // with other manager singletons.
     }
 
-    /// Allocate a DMA buffer
+    /// Allocate a DMA buffer, reusable from anywhere in physical memory.
     pub fn allocate_dma_buffer(&mut self, size: usize, alignment: usize) -> Result<(u64, Capability), DriverError> {
-        // For now, we'll just allocate a new region
-        // In a real implementation, we would look for free regions first
-        
-        let page_size = 4096;
-        let pages_needed = (size + page_size - 1) / page_size;
-        
-        // Allocate physical pages
-        let physical_address = match alloc_pages(pages_needed) {
-            Some(addr) => addr as u64,
-            None => return Err(DriverError::OutOfMemory),
+        self.allocate_dma_buffer_in_zone(size, alignment, DmaZone::Any)
+    }
+
+    /// Allocate a DMA buffer constrained to `zone`. Reuses a freed region
+    /// from `free_regions` when one is a big enough, correctly-aligned fit,
+    /// and only calls `alloc_pages` when nothing in the free list qualifies.
+    pub fn allocate_dma_buffer_in_zone(
+        &mut self,
+        size: usize,
+        alignment: usize,
+        zone: DmaZone,
+    ) -> Result<(u64, Capability), DriverError> {
+        let alignment = if alignment == 0 { 1 } else { alignment };
+        let aligned_size = (size + PAGE_SIZE - 1) & !(PAGE_SIZE - 1);
+
+        let mut region = match self.take_free_region(aligned_size, alignment, zone) {
+            Some(region) => region,
+            None => self.alloc_fresh_region(aligned_size, alignment, zone)?,
         };
-        
+        region.allocated = true;
+
         // For simplicity, we'll use the same value for virtual address
         // In a real implementation, we would map this to kernel virtual address space
-        let virtual_address = physical_address as usize;
-        
-        let region = DmaRegion {
-            physical_address,
-            virtual_address,
-            size: pages_needed * page_size,
-            allocated: true,
-        };
-        
-        // Create a DMA buffer capability
         let capability = Capability::new_dma_buffer(
             region.physical_address,
             region.virtual_address,
-            region.size
+            region.size,
         );
-        
-        self.allocated_regions.insert(region.physical_address, region);
-        
+        let physical_address = region.physical_address;
+
+        self.allocated_regions.insert(physical_address, region);
+
         Ok((physical_address, capability))
     }
 
-    /// Deallocate a DMA buffer
+    /// Deallocate a DMA buffer, returning it to the free list and merging it
+    /// with any adjacent free region to keep fragmentation down.
     pub fn deallocate_dma_buffer(&mut self, phys_addr: u64) -> Result<(), DriverError> {
         if let Some(mut region) = self.allocated_regions.remove(&phys_addr) {
             region.allocated = false;
-            self.free_regions.push(region);
+            self.insert_free_region(region);
             Ok(())
         } else {
             Err(DriverError::InvalidArgument)
         }
     }
+
+    /// Best-fit scan of `free_regions`: pick the smallest region whose size
+    /// covers `size` and whose `physical_address` already satisfies
+    /// `alignment` and `zone`, splitting the leftover tail back into the
+    /// free list. Returns `None` if nothing qualifies.
+    fn take_free_region(&mut self, size: usize, alignment: usize, zone: DmaZone) -> Option<DmaRegion> {
+        let mut best: Option<usize> = None;
+        for (i, candidate) in self.free_regions.iter().enumerate() {
+            if candidate.size < size {
+                continue;
+            }
+            if candidate.physical_address % alignment as u64 != 0 {
+                continue;
+            }
+            if !zone.fits(candidate.physical_address, size) {
+                continue;
+            }
+            let is_better = match best {
+                Some(b) => candidate.size < self.free_regions[b].size,
+                None => true,
+            };
+            if is_better {
+                best = Some(i);
+            }
+        }
+
+        let mut region = self.free_regions.remove(best?);
+        let leftover = region.size - size;
+        if leftover > 0 {
+            let tail = DmaRegion {
+                physical_address: region.physical_address + size as u64,
+                virtual_address: region.virtual_address + size,
+                size: leftover,
+                allocated: false,
+            };
+            region.size = size;
+            self.insert_free_region(tail);
+        }
+        Some(region)
+    }
+
+    /// Fall back to fresh physical pages when the free list has no fit,
+    /// over-allocating to satisfy alignments coarser than a page and
+    /// handing any slack pages back to the free list.
+    fn alloc_fresh_region(&mut self, size: usize, alignment: usize, zone: DmaZone) -> Result<DmaRegion, DriverError> {
+        let align = core::cmp::max(alignment, PAGE_SIZE);
+        let slack = align - PAGE_SIZE;
+        let pages_needed = (size + slack + PAGE_SIZE - 1) / PAGE_SIZE;
+
+        let raw_address = match alloc_pages(pages_needed) {
+            Some(addr) => addr as u64,
+            None => return Err(DriverError::OutOfMemory),
+        };
+
+        let aligned_address = (raw_address + align as u64 - 1) & !(align as u64 - 1);
+        if !zone.fits(aligned_address, size) {
+            free_pages(raw_address as usize, pages_needed);
+            return Err(DriverError::OutOfMemory);
+        }
+
+        let total_size = pages_needed * PAGE_SIZE;
+        let front_slack = (aligned_address - raw_address) as usize;
+        let back_slack = total_size - front_slack - size;
+
+        if front_slack > 0 {
+            self.insert_free_region(DmaRegion {
+                physical_address: raw_address,
+                virtual_address: raw_address as usize,
+                size: front_slack,
+                allocated: false,
+            });
+        }
+        if back_slack > 0 {
+            let back_address = aligned_address + size as u64;
+            self.insert_free_region(DmaRegion {
+                physical_address: back_address,
+                virtual_address: back_address as usize,
+                size: back_slack,
+                allocated: false,
+            });
+        }
+
+        Ok(DmaRegion {
+            physical_address: aligned_address,
+            virtual_address: aligned_address as usize,
+            size,
+            allocated: false,
+        })
+    }
+
+    /// Insert `region` into `free_regions` in physical-address order,
+    /// merging it with the immediately preceding and/or following free
+    /// region so adjacent freed buffers recombine into one larger one.
+    fn insert_free_region(&mut self, mut region: DmaRegion) {
+        let pos = self.free_regions.partition_point(|r| r.physical_address < region.physical_address);
+        if pos > 0 && self.free_regions[pos - 1].physical_address + self.free_regions[pos - 1].size as u64 == region.physical_address {
+            let prev = self.free_regions.remove(pos - 1);
+            region.physical_address = prev.physical_address;
+            region.virtual_address = prev.virtual_address;
+            region.size += prev.size;
+        }
+
+        let pos = self.free_regions.partition_point(|r| r.physical_address < region.physical_address);
+        if pos < self.free_regions.len() && region.physical_address + region.size as u64 == self.free_regions[pos].physical_address {
+            let next = self.free_regions.remove(pos);
+            region.size += next.size;
+        }
+
+        let pos = self.free_regions.partition_point(|r| r.physical_address < region.physical_address);
+        self.free_regions.insert(pos, region);
+    }
 }
 
 /// 全局DMA分配器单例实例
@@ -119,3 +255,88 @@ pub fn get_dma_allocator() -> &'static Mutex<DmaAllocator> {
         DMA_ALLOCATOR.as_ref().expect("DMA allocator has not been initialized")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn region(addr: u64, size: usize) -> DmaRegion {
+        DmaRegion { physical_address: addr, virtual_address: addr as usize, size, allocated: false }
+    }
+
+    #[test]
+    fn test_insert_free_region_merges_adjacent_prev() {
+        let mut alloc = DmaAllocator::new();
+        alloc.insert_free_region(region(0x1000, 0x1000));
+        alloc.insert_free_region(region(0x2000, 0x1000));
+
+        assert_eq!(alloc.free_regions.len(), 1);
+        assert_eq!(alloc.free_regions[0].physical_address, 0x1000);
+        assert_eq!(alloc.free_regions[0].size, 0x2000);
+    }
+
+    #[test]
+    fn test_insert_free_region_merges_adjacent_next() {
+        let mut alloc = DmaAllocator::new();
+        alloc.insert_free_region(region(0x2000, 0x1000));
+        alloc.insert_free_region(region(0x1000, 0x1000));
+
+        assert_eq!(alloc.free_regions.len(), 1);
+        assert_eq!(alloc.free_regions[0].physical_address, 0x1000);
+        assert_eq!(alloc.free_regions[0].size, 0x2000);
+    }
+
+    #[test]
+    fn test_insert_free_region_merges_both_neighbors() {
+        let mut alloc = DmaAllocator::new();
+        alloc.insert_free_region(region(0x1000, 0x1000));
+        alloc.insert_free_region(region(0x3000, 0x1000));
+        // Filling the gap between them should coalesce all three into one.
+        alloc.insert_free_region(region(0x2000, 0x1000));
+
+        assert_eq!(alloc.free_regions.len(), 1);
+        assert_eq!(alloc.free_regions[0].physical_address, 0x1000);
+        assert_eq!(alloc.free_regions[0].size, 0x3000);
+    }
+
+    #[test]
+    fn test_insert_free_region_does_not_merge_non_adjacent() {
+        let mut alloc = DmaAllocator::new();
+        alloc.insert_free_region(region(0x1000, 0x1000));
+        alloc.insert_free_region(region(0x4000, 0x1000));
+
+        assert_eq!(alloc.free_regions.len(), 2);
+    }
+
+    #[test]
+    fn test_take_free_region_best_fit_splits_leftover() {
+        let mut alloc = DmaAllocator::new();
+        alloc.insert_free_region(region(0x1000, 0x3000));
+        alloc.insert_free_region(region(0x10000, 0x1000));
+
+        // The exact-size region should win over the larger one, even though
+        // it was inserted second.
+        let taken = alloc.take_free_region(0x1000, 0x1000, DmaZone::Any).expect("fit");
+        assert_eq!(taken.physical_address, 0x10000);
+        assert_eq!(taken.size, 0x1000);
+
+        // The bigger region is still there, untouched.
+        assert_eq!(alloc.free_regions.len(), 1);
+        assert_eq!(alloc.free_regions[0].physical_address, 0x1000);
+        assert_eq!(alloc.free_regions[0].size, 0x3000);
+    }
+
+    #[test]
+    fn test_take_free_region_respects_alignment_and_zone() {
+        let mut alloc = DmaAllocator::new();
+        alloc.insert_free_region(region(0x1_0000_2000, 0x1000)); // above 4G
+        alloc.insert_free_region(region(0x2000, 0x2000));
+
+        // The above-4G region must be skipped for a Below4G request even
+        // though it's otherwise a valid fit, and the leftover tail of the
+        // chosen region must go back into the free list.
+        let taken = alloc.take_free_region(0x1000, 0x2000, DmaZone::Below4G).expect("fit");
+        assert_eq!(taken.physical_address, 0x2000);
+        assert_eq!(alloc.free_regions.len(), 2);
+    }
+}
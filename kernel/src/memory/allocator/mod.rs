@@ -12,4 +12,4 @@ pub mod dma;
 // Re-export commonly used types and functions
 pub use buddy::{BuddyAllocator, ALLOCATOR as BUDDY_ALLOCATOR, get_buddy_allocator};
 pub use slab::{SlabAllocator, SLAB_ALLOCATOR, get_slab_allocator};
-pub use dma::{DmaAllocator, DmaRegion, init_dma_allocator, get_dma_allocator};
\ No newline at end of file
+pub use dma::{DmaAllocator, DmaRegion, DmaZone, init_dma_allocator, get_dma_allocator};
\ No newline at end of file
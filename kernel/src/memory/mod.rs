@@ -12,6 +12,7 @@
 
 pub mod physical;
 pub mod virt;
+pub mod virtual_;
 pub mod protection;
 pub mod allocator;
 
@@ -0,0 +1,277 @@
+//! Demand-paged user address spaces
+//!
+//! Backs the bootstrap ELF loader's user mappings: a small 3-level
+//! AArch64 page-table walker scoped to a single user page table (as
+//! opposed to `virt`, which manages the kernel's own address space),
+//! plus a VMA table so pages can be faulted in on first touch instead
+//! of being allocated and copied eagerly by the loader.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use shared::sync::mutex::Mutex;
+
+use crate::arch::common::mmu::{ArchType, MmuFlags};
+use crate::memory::physical::{alloc_pages, PhysAddr};
+
+pub type VirtAddr = usize;
+
+const PAGE_SIZE_4K: usize = 4096;
+const MAX_VMAS: usize = 64;
+
+static PT_LOCK: Mutex<()> = Mutex::new(());
+static VMA_TABLE: Mutex<[(usize, Option<Vma>); MAX_VMAS]> = Mutex::new([(0, None); MAX_VMAS]);
+
+/// File-backing metadata for a demand-paged segment, letting
+/// `handle_page_fault` copy the right slice of an ELF image into a page
+/// the first time it's touched instead of the loader copying everything
+/// up front. `data` holds exactly `filesz` bytes of the segment's file
+/// content; `seg_vaddr` is the segment's (bias-applied) `p_vaddr`, which
+/// may not be page aligned.
+#[derive(Clone)]
+pub struct FileBacking {
+    pub data: Vec<u8>,
+    pub seg_vaddr: usize,
+    pub filesz: usize,
+    pub memsz: usize,
+}
+
+/// A lazily-backed virtual memory area within one user page table.
+#[derive(Clone)]
+pub struct Vma {
+    pub base: usize,
+    pub end: usize,
+    pub flags: MmuFlags,
+    pub file: Option<FileBacking>,
+}
+
+/// Allocate and zero a fresh L1 table for a new user address space.
+pub fn create_user_l1() -> Option<usize> {
+    let l1_pa = alloc_pages(1)?;
+    unsafe {
+        core::ptr::write_bytes(l1_pa as *mut u8, 0, PAGE_SIZE_4K);
+    }
+    Some(l1_pa)
+}
+
+fn l1_index(vaddr: VirtAddr) -> usize {
+    (vaddr >> 30) & 0x1FF
+}
+
+fn l2_index(vaddr: VirtAddr) -> usize {
+    (vaddr >> 21) & 0x1FF
+}
+
+fn l3_index(vaddr: VirtAddr) -> usize {
+    (vaddr >> 12) & 0x1FF
+}
+
+/// Walk (allocating intermediate tables as needed) to the L3 table that
+/// would hold `vaddr`'s leaf entry in the address space rooted at
+/// `pt_base`. 3-level, 4KB-granule, 39-bit VA layout (T0SZ=25).
+unsafe fn ensure_l3_table_in_pt(pt_base: usize, vaddr: VirtAddr) -> Option<*mut u64> {
+    let l1 = pt_base as *mut u64;
+    let l1ent = core::ptr::read_volatile(l1.add(l1_index(vaddr)));
+    let l2_pa = if l1ent & 0x3 == 3 {
+        (l1ent & !((PAGE_SIZE_4K as u64) - 1)) as usize
+    } else {
+        let pa = alloc_pages(1)?;
+        core::ptr::write_bytes(pa as *mut u8, 0, PAGE_SIZE_4K);
+        let desc = ((pa as u64) & !((PAGE_SIZE_4K as u64) - 1)) | 3u64;
+        core::ptr::write_volatile(l1.add(l1_index(vaddr)), desc);
+        pa
+    };
+
+    let l2 = l2_pa as *mut u64;
+    let l2ent = core::ptr::read_volatile(l2.add(l2_index(vaddr)));
+    if l2ent & 0x3 == 3 {
+        Some((l2ent & !((PAGE_SIZE_4K as u64) - 1)) as *mut u64)
+    } else {
+        let pa = alloc_pages(1)?;
+        core::ptr::write_bytes(pa as *mut u8, 0, PAGE_SIZE_4K);
+        let desc = ((pa as u64) & !((PAGE_SIZE_4K as u64) - 1)) | 3u64;
+        core::ptr::write_volatile(l2.add(l2_index(vaddr)), desc);
+        Some(pa as *mut u64)
+    }
+}
+
+/// Map a single 4KB page in the address space rooted at `pt_base`,
+/// allocating intermediate L2/L3 tables as needed. Overwrites any
+/// existing leaf entry for `vaddr`.
+pub fn map_in_pt(pt_base: usize, vaddr: VirtAddr, paddr: PhysAddr, flags: MmuFlags) {
+    let _g = PT_LOCK.lock();
+    let attrs = (1u64 << 10) | (2u64 << 8) | flags.to_arch(ArchType::AArch64);
+    unsafe {
+        if let Some(l3) = ensure_l3_table_in_pt(pt_base, vaddr) {
+            let entry = ((paddr as u64) & !((PAGE_SIZE_4K as u64) - 1)) | 3u64 | attrs;
+            core::ptr::write_volatile(l3.add(l3_index(vaddr)), entry);
+            core::arch::asm!("dsb ish", "isb");
+        }
+    }
+}
+
+/// Change the permission bits of an already-mapped page without touching
+/// its physical address, e.g. clearing `WRITE` once `PT_GNU_RELRO`
+/// relocations have finished patching it.
+pub fn remap_in_pt(pt_base: usize, vaddr: VirtAddr, new_flags: MmuFlags) {
+    let _g = PT_LOCK.lock();
+    unsafe {
+        if let Some(l3) = ensure_l3_table_in_pt(pt_base, vaddr) {
+            let idx3 = l3_index(vaddr);
+            let entry = core::ptr::read_volatile(l3.add(idx3));
+            if entry & 0x3 != 0 {
+                let paddr = entry & !0xFFF;
+                let attrs = (1u64 << 10) | (2u64 << 8) | new_flags.to_arch(ArchType::AArch64);
+                core::ptr::write_volatile(l3.add(idx3), paddr | 3u64 | attrs);
+                core::arch::asm!("dsb ish", "isb");
+            }
+        }
+    }
+}
+
+/// Look up the physical page and flags currently mapped at `vaddr` in
+/// `pt_base`'s address space, without allocating anything.
+pub fn query_mapping_in_pt(pt_base: usize, vaddr: VirtAddr) -> Option<(PhysAddr, MmuFlags)> {
+    let _g = PT_LOCK.lock();
+    let page = vaddr & !(PAGE_SIZE_4K - 1);
+    unsafe {
+        let l1ent = core::ptr::read_volatile((pt_base as *const u64).add(l1_index(page)));
+        if l1ent & 0x3 != 3 {
+            return None;
+        }
+        let l2 = (l1ent & !((PAGE_SIZE_4K as u64) - 1)) as *const u64;
+        let l2ent = core::ptr::read_volatile(l2.add(l2_index(page)));
+        if l2ent & 0x3 != 3 {
+            return None;
+        }
+        let l3 = (l2ent & !((PAGE_SIZE_4K as u64) - 1)) as *const u64;
+        let entry = core::ptr::read_volatile(l3.add(l3_index(page)));
+        if entry & 0x3 != 0 {
+            Some(((entry & !0xFFF) as PhysAddr, MmuFlags::from_arch(entry, ArchType::AArch64)))
+        } else {
+            None
+        }
+    }
+}
+
+/// Register a demand-paged VMA backed by a slice of an ELF image, so
+/// `handle_page_fault` can copy the segment's file content in on first
+/// touch instead of the caller mapping every page eagerly.
+pub fn vma_add_file(pt_base: usize, base: usize, size: usize, flags: MmuFlags, file: FileBacking) {
+    let mut tbl = VMA_TABLE.lock();
+    let end = base.saturating_add(size);
+    for slot in tbl.iter_mut() {
+        if slot.1.is_none() {
+            *slot = (pt_base, Some(Vma { base, end, flags, file }));
+            return;
+        }
+    }
+}
+
+/// Ensure the page containing `vaddr` is resident in `pt`'s address
+/// space, faulting it in via `handle_page_fault` if it isn't mapped yet.
+/// Returns the backing physical page. Used by the ELF loader to
+/// materialize pages that need relocations patched into them
+/// immediately, without duplicating the fault-in logic.
+pub fn ensure_page_resident(pt: usize, vaddr: usize) -> Option<usize> {
+    let va = vaddr & !(PAGE_SIZE_4K - 1);
+    if let Some((pa, _)) = query_mapping_in_pt(pt, va) {
+        return Some(pa);
+    }
+    if handle_page_fault(pt, va, 0) {
+        query_mapping_in_pt(pt, va).map(|(pa, _)| pa)
+    } else {
+        None
+    }
+}
+
+/// Translation-fault handler: find the VMA owning `vaddr` in `pt`'s
+/// address space, allocate a page, map it with the VMA's flags, and
+/// fill it from the VMA's file backing (or zero it, for anonymous
+/// VMAs / the BSS tail past `filesz`). Returns whether a VMA was found
+/// and the fault resolved.
+pub fn handle_page_fault(pt: usize, vaddr: usize, _esr: u64) -> bool {
+    let va = vaddr & !(PAGE_SIZE_4K - 1);
+    let vma = {
+        let tbl = VMA_TABLE.lock();
+        tbl.iter()
+            .find(|(owner, entry)| *owner == pt && entry.as_ref().is_some_and(|v| va >= v.base && va < v.end))
+            .and_then(|(_, entry)| entry.clone())
+    };
+    let vma = match vma {
+        Some(v) => v,
+        None => return false,
+    };
+
+    let pa = match alloc_pages(1) {
+        Some(pa) => pa,
+        None => return false,
+    };
+
+    map_in_pt(pt, va, pa, vma.flags);
+
+    unsafe {
+        let dst = pa as *mut u8;
+        match &vma.file {
+            Some(file) => {
+                let seg_mem_off = va.saturating_sub(file.seg_vaddr);
+                let dst_off = file.seg_vaddr.saturating_sub(va);
+                if seg_mem_off < file.memsz {
+                    let remain_in_seg = file.memsz - seg_mem_off;
+                    let to_copy = core::cmp::min(PAGE_SIZE_4K - dst_off, remain_in_seg);
+                    let file_avail = if seg_mem_off < file.filesz {
+                        core::cmp::min(to_copy, file.filesz - seg_mem_off)
+                    } else {
+                        0
+                    };
+                    if dst_off > 0 {
+                        core::ptr::write_bytes(dst, 0, dst_off);
+                    }
+                    if file_avail > 0 {
+                        core::ptr::copy_nonoverlapping(file.data.as_ptr().add(seg_mem_off), dst.add(dst_off), file_avail);
+                    }
+                    if file_avail < to_copy {
+                        core::ptr::write_bytes(dst.add(dst_off + file_avail), 0, to_copy - file_avail);
+                    }
+                    if dst_off + to_copy < PAGE_SIZE_4K {
+                        core::ptr::write_bytes(dst.add(dst_off + to_copy), 0, PAGE_SIZE_4K - (dst_off + to_copy));
+                    }
+                } else {
+                    core::ptr::write_bytes(dst, 0, PAGE_SIZE_4K);
+                }
+            }
+            None => core::ptr::write_bytes(dst, 0, PAGE_SIZE_4K),
+        }
+    }
+
+    clean_dcache_range(pa, PAGE_SIZE_4K);
+
+    unsafe {
+        core::arch::asm!("dsb ish");
+        let ttbr0: u64;
+        core::arch::asm!("mrs {}, ttbr0_el1", out(reg) ttbr0);
+        let asid = (ttbr0 >> 48) & 0xFFFF;
+        let va_bits = (va as u64 >> 12) & 0xFFFF_FFFF_FFFF;
+        core::arch::asm!("tlbi vae1is, {}", in(reg) (va_bits | (asid << 48)));
+        core::arch::asm!("dsb ish", "isb");
+    }
+
+    true
+}
+
+/// Clean (write back) `size` bytes starting at `addr` from the data
+/// cache, so a page this loader just populated through a direct
+/// physical-address write is visible to instruction fetch / the MMU
+/// walker without waiting for natural eviction.
+fn clean_dcache_range(addr: usize, size: usize) {
+    const CACHE_LINE: usize = 64;
+    let mut a = addr & !(CACHE_LINE - 1);
+    let end = addr + size;
+    unsafe {
+        while a < end {
+            core::arch::asm!("dc cvac, {0}", in(reg) a);
+            a += CACHE_LINE;
+        }
+        core::arch::asm!("dsb sy");
+    }
+}
@@ -0,0 +1,202 @@
+//! Minimal user-space IPC client.
+//!
+//! `hnx_libc` only exposes the raw channel syscalls (create/read/write);
+//! this module layers the endpoint/priority/region vocabulary the socket
+//! and packet-forwarding code needs on top of a channel handle per
+//! endpoint, mirroring the shape of the kernel's own `core::ipc` types so
+//! the two sides agree on what a message looks like.
+//!
+//! Shared regions are NOT cross-process: there is no VMO-handle syscall
+//! anywhere in `hnx_libc`, so a region would only ever be stashed in this
+//! process's own `REGIONS` map, and a `RegionTransfer` carried in an
+//! `IpcMessage` to a different process's endpoint (e.g. this server's
+//! `DRIVER_EPID`, which is a separate `device-driver-server` binary) would
+//! name a region that peer never heard of, so its `region_read` would
+//! always return `Err`. Because of that, nothing in this server creates
+//! regions today — `main.rs::send_to_driver` sends every outgoing frame
+//! inline and drops ones too big for the fixed 256-byte message body
+//! rather than routing them through a region path that's documented to
+//! always fail on the far end. `region_read` is kept for the symmetric
+//! inbound case (`handle_packet_in`'s `msg.region` branch), ready for
+//! whichever side creates the region once a kernel VMO-handoff syscall
+//! makes cross-process regions real.
+
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicU64, Ordering};
+use spin::Mutex;
+
+use hnx_libc::syscalls::{sys_channel_create, sys_channel_read, sys_channel_write};
+
+/// Endpoint identifier, backed by a kernel channel handle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EndpointId(pub u32);
+
+/// Priority levels for messages, mirroring `kernel::core::ipc::Priority`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Priority {
+    Low,
+    Normal,
+    High,
+    Critical,
+}
+
+/// Unique identifier for a shared memory region.
+pub type RegionId = u32;
+
+/// A reference to a byte range within a shared region, carried by an
+/// `IpcMessage` in place of inline data for zero-copy bulk transfers.
+#[derive(Debug, Clone, Copy)]
+pub struct RegionTransfer {
+    pub region: RegionId,
+    pub offset: usize,
+    pub len: usize,
+}
+
+/// A message exchanged with the kernel over an endpoint's channel.
+#[derive(Clone)]
+pub struct IpcMessage {
+    pub src_pid: u32,
+    pub dst_epid: EndpointId,
+    pub op: u64,
+    pub priority: Priority,
+    pub data_len: usize,
+    pub data: [u8; 256],
+    pub timestamp: u64,
+    pub region: Option<RegionTransfer>,
+}
+
+/// Error type for IPC client operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IpcError(pub i32);
+
+/// Create a new endpoint (a fresh channel).
+pub fn endpoint_create() -> Result<EndpointId, IpcError> {
+    let mut out0: u32 = 0;
+    let mut out1: u32 = 0;
+    let rc = unsafe { sys_channel_create(0, &mut out0, &mut out1) };
+    if rc < 0 {
+        return Err(IpcError(rc));
+    }
+    Ok(EndpointId(out0))
+}
+
+/// Wire layout for a message: priority(1) + op(8) + timestamp(8) +
+/// region-present(1) + region(region:4, offset:8, len:8) + data_len(4) +
+/// data(data_len).
+fn encode(msg: &IpcMessage, buf: &mut [u8; 512]) -> usize {
+    buf[0] = msg.priority as u8;
+    buf[1..9].copy_from_slice(&msg.op.to_le_bytes());
+    buf[9..17].copy_from_slice(&msg.timestamp.to_le_bytes());
+    let mut off = 17;
+    match msg.region {
+        Some(r) => {
+            buf[off] = 1;
+            buf[off + 1..off + 5].copy_from_slice(&r.region.to_le_bytes());
+            buf[off + 5..off + 13].copy_from_slice(&(r.offset as u64).to_le_bytes());
+            buf[off + 13..off + 21].copy_from_slice(&(r.len as u64).to_le_bytes());
+            off += 21;
+        }
+        None => {
+            buf[off] = 0;
+            off += 21;
+        }
+    }
+    buf[off..off + 4].copy_from_slice(&(msg.data_len as u32).to_le_bytes());
+    off += 4;
+    buf[off..off + msg.data_len].copy_from_slice(&msg.data[..msg.data_len]);
+    off + msg.data_len
+}
+
+fn decode(src_pid: u32, dst_epid: EndpointId, buf: &[u8], n: usize) -> Option<IpcMessage> {
+    if n < 21 + 4 {
+        return None;
+    }
+    let priority = match buf[0] {
+        0 => Priority::Low,
+        2 => Priority::High,
+        3 => Priority::Critical,
+        _ => Priority::Normal,
+    };
+    let op = u64::from_le_bytes(buf[1..9].try_into().ok()?);
+    let timestamp = u64::from_le_bytes(buf[9..17].try_into().ok()?);
+    let mut off = 17;
+    let region = if buf[off] == 1 {
+        let region = u32::from_le_bytes(buf[off + 1..off + 5].try_into().ok()?);
+        let offset = u64::from_le_bytes(buf[off + 5..off + 13].try_into().ok()?) as usize;
+        let len = u64::from_le_bytes(buf[off + 13..off + 21].try_into().ok()?) as usize;
+        off += 21;
+        Some(RegionTransfer { region, offset, len })
+    } else {
+        off += 21;
+        None
+    };
+    let data_len = u32::from_le_bytes(buf[off..off + 4].try_into().ok()?) as usize;
+    off += 4;
+    if off + data_len > n || data_len > 256 {
+        return None;
+    }
+    let mut data = [0u8; 256];
+    data[..data_len].copy_from_slice(&buf[off..off + data_len]);
+    Some(IpcMessage { src_pid, dst_epid, op, priority, data_len, data, timestamp, region })
+}
+
+/// Send a message on an endpoint's channel.
+pub fn endpoint_send_sync(dst_epid: EndpointId, msg: IpcMessage, _timeout_ms: Option<u64>) -> Result<(), IpcError> {
+    let mut wire = [0u8; 512];
+    let len = encode(&msg, &mut wire);
+    let rc = unsafe { sys_channel_write(dst_epid.0 as usize, 0, wire.as_ptr(), len, 0, 0) };
+    if rc < 0 {
+        return Err(IpcError(rc));
+    }
+    Ok(())
+}
+
+/// Receive the next message from an endpoint's channel.
+pub fn endpoint_recv_sync(epid: EndpointId, _timeout_ms: Option<u64>) -> Result<IpcMessage, IpcError> {
+    let mut wire = [0u8; 512];
+    let mut actual_bytes: usize = 0;
+    let mut actual_handles: usize = 0;
+    let rc = unsafe {
+        sys_channel_read(epid.0 as usize, 0, wire.as_mut_ptr(), 0, wire.len(), 0, &mut actual_bytes, &mut actual_handles)
+    };
+    if rc < 0 {
+        return Err(IpcError(rc));
+    }
+    decode(0, epid, &wire, actual_bytes).ok_or(IpcError(-1))
+}
+
+struct Region {
+    data: Mutex<Vec<u8>>,
+}
+
+static REGIONS: Mutex<BTreeMap<RegionId, Region>> = Mutex::new(BTreeMap::new());
+
+/// Copy bytes out of a region into `buf`, honoring the transfer's
+/// offset/length and rejecting anything out of bounds. Nothing in this
+/// process creates regions today (see the module doc comment), so this
+/// only ever returns `Err` until a kernel VMO-handoff syscall lets a peer
+/// populate `REGIONS` for real; kept so `handle_packet_in`'s `msg.region`
+/// branch has something to call once that lands.
+pub fn region_read(_epid: u32, transfer: &RegionTransfer, buf: &mut [u8]) -> Result<usize, IpcError> {
+    let regions = REGIONS.lock();
+    let region = regions.get(&transfer.region).ok_or(IpcError(-1))?;
+    let data = region.data.lock();
+    let end = transfer.offset.checked_add(transfer.len).ok_or(IpcError(-1))?;
+    if end > data.len() {
+        return Err(IpcError(-1));
+    }
+    let n = buf.len().min(transfer.len);
+    buf[..n].copy_from_slice(&data[transfer.offset..transfer.offset + n]);
+    Ok(n)
+}
+
+/// Monotonically increasing placeholder timestamp, until a clock syscall
+/// exists for user-space to query kernel time directly.
+static LOGICAL_CLOCK: AtomicU64 = AtomicU64::new(0);
+
+pub fn now_us() -> u64 {
+    LOGICAL_CLOCK.fetch_add(1, Ordering::Relaxed)
+}
@@ -0,0 +1,203 @@
+//! Boot-time network configuration for the interface this server drives.
+//!
+//! `NetworkServer::new` used to hardcode the board's MAC and IPv4 address,
+//! so the same image couldn't be deployed across boards that differ only
+//! in addressing. `InterfaceManager` now resolves a `NetSettings` from
+//! `key=value` boot configuration lines (`ip`, `ip6`, `mac`), falling back
+//! to the compiled defaults below for any key that's absent or malformed.
+
+extern crate alloc;
+
+use alloc::string::String;
+use hnx_libc::syscalls::{sys_close, sys_open, sys_read};
+use shared::abi::constants::O_RDONLY;
+use smoltcp::wire::{EthernetAddress, Ipv4Address, Ipv4Cidr, Ipv6Address, Ipv6Cidr};
+
+/// Boot-config file read by `read_boot_config`, one `key=value` setting per
+/// line. Populated by whatever lays out the initrd; absent entirely on
+/// boards that only want the compiled defaults.
+const BOOT_CONFIG_PATH: &str = "/etc/netconfig\0";
+
+/// Compiled-in defaults, used for any setting the boot configuration
+/// doesn't override. Matches the QEMU `virt` board's user-mode networking.
+const DEFAULT_MAC: [u8; 6] = [0x02, 0x00, 0x00, 0x00, 0x00, 0x01];
+const DEFAULT_IPV4: Ipv4Address = Ipv4Address::new(10, 0, 2, 15);
+const DEFAULT_IPV4_PREFIX: u8 = 24;
+
+/// Addressing this server's interface should come up with, resolved from
+/// boot configuration with compiled defaults filling in anything absent.
+pub struct NetSettings {
+    pub ipv4: Option<Ipv4Cidr>,
+    pub ipv6: Option<Ipv6Cidr>,
+    pub mac: EthernetAddress,
+}
+
+impl Default for NetSettings {
+    fn default() -> Self {
+        Self {
+            ipv4: Some(Ipv4Cidr::new(DEFAULT_IPV4, DEFAULT_IPV4_PREFIX)),
+            ipv6: None,
+            mac: EthernetAddress(DEFAULT_MAC),
+        }
+    }
+}
+
+impl NetSettings {
+    /// Parse `key=value` lines (one setting per line; blank lines and
+    /// unrecognized or malformed keys are ignored), overlaying onto the
+    /// compiled defaults.
+    pub fn parse(config: &str) -> Self {
+        let mut settings = Self::default();
+        for line in config.lines() {
+            let Some((key, value)) = line.trim().split_once('=') else {
+                continue;
+            };
+            match key.trim() {
+                "ip" => {
+                    if let Some(cidr) = parse_ipv4_cidr(value.trim()) {
+                        settings.ipv4 = Some(cidr);
+                    }
+                }
+                "ip6" => {
+                    if let Some(cidr) = parse_ipv6_cidr(value.trim()) {
+                        settings.ipv6 = Some(cidr);
+                    }
+                }
+                "mac" => {
+                    if let Some(mac) = parse_mac(value.trim()) {
+                        settings.mac = mac;
+                    }
+                }
+                _ => {}
+            }
+        }
+        settings
+    }
+}
+
+/// Parse a `a.b.c.d/prefix` string.
+fn parse_ipv4_cidr(s: &str) -> Option<Ipv4Cidr> {
+    let (addr, prefix) = s.split_once('/')?;
+    let prefix: u8 = prefix.parse().ok()?;
+    let mut octets = [0u8; 4];
+    let mut parts = addr.split('.');
+    for octet in octets.iter_mut() {
+        *octet = parts.next()?.parse().ok()?;
+    }
+    if parts.next().is_some() {
+        return None;
+    }
+    Some(Ipv4Cidr::new(Ipv4Address::new(octets[0], octets[1], octets[2], octets[3]), prefix))
+}
+
+/// Parse an uncompressed `a:b:c:d:e:f:g:h/prefix` string. Unlike a real
+/// IPv6 parser this doesn't accept `::` zero-run compression, which is
+/// fine for a boot-config value but not a general-purpose parser.
+fn parse_ipv6_cidr(s: &str) -> Option<Ipv6Cidr> {
+    let (addr, prefix) = s.split_once('/')?;
+    let prefix: u8 = prefix.parse().ok()?;
+    let mut groups = [0u16; 8];
+    let mut parts = addr.split(':');
+    for group in groups.iter_mut() {
+        *group = u16::from_str_radix(parts.next()?, 16).ok()?;
+    }
+    if parts.next().is_some() {
+        return None;
+    }
+    Some(Ipv6Cidr::new(
+        Ipv6Address::new(
+            groups[0], groups[1], groups[2], groups[3], groups[4], groups[5], groups[6], groups[7],
+        ),
+        prefix,
+    ))
+}
+
+/// Parse a colon-separated MAC address, e.g. `02:00:00:00:00:01`.
+fn parse_mac(s: &str) -> Option<EthernetAddress> {
+    let mut octets = [0u8; 6];
+    let mut parts = s.split(':');
+    for octet in octets.iter_mut() {
+        *octet = u8::from_str_radix(parts.next()?, 16).ok()?;
+    }
+    if parts.next().is_some() {
+        return None;
+    }
+    Some(EthernetAddress(octets))
+}
+
+/// Resolves and owns the boot-time network settings for the interface this
+/// server drives.
+pub struct InterfaceManager {
+    settings: NetSettings,
+}
+
+impl InterfaceManager {
+    /// Resolve settings from the boot configuration source, falling back
+    /// to compiled defaults for anything it doesn't specify.
+    pub fn new() -> Self {
+        Self {
+            settings: NetSettings::parse(&Self::read_boot_config()),
+        }
+    }
+
+    /// Read `BOOT_CONFIG_PATH` through the standard `open`/`read` syscalls.
+    /// Any failure — the file doesn't exist on this board's initrd, the
+    /// path isn't served yet, a read that fails or returns no bytes —
+    /// resolves to an empty string, which `NetSettings::parse` treats as
+    /// "use the compiled defaults". Only the first 512 bytes are read.
+    fn read_boot_config() -> String {
+        let mut buf = [0u8; 512];
+        unsafe {
+            let fd = sys_open(BOOT_CONFIG_PATH.as_ptr(), O_RDONLY as i32, 0);
+            if fd < 0 {
+                return String::new();
+            }
+            let n = sys_read(fd, buf.as_mut_ptr(), buf.len());
+            sys_close(fd);
+            if n <= 0 {
+                return String::new();
+            }
+            String::from_utf8_lossy(&buf[..n as usize]).into_owned()
+        }
+    }
+
+    pub fn settings(&self) -> &NetSettings {
+        &self.settings
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_defaults_when_empty() {
+        let settings = NetSettings::parse("");
+        assert_eq!(settings.mac, NetSettings::default().mac);
+        assert!(settings.ipv4.is_some());
+        assert!(settings.ipv6.is_none());
+    }
+
+    #[test]
+    fn test_parses_mac_and_ip() {
+        let settings = NetSettings::parse("mac=02:00:00:00:00:02\nip=192.168.1.5/24\n");
+        assert_eq!(settings.mac, EthernetAddress([0x02, 0, 0, 0, 0, 0x02]));
+        assert_eq!(settings.ipv4, Some(Ipv4Cidr::new(Ipv4Address::new(192, 168, 1, 5), 24)));
+    }
+
+    #[test]
+    fn test_parses_ip6() {
+        let settings = NetSettings::parse("ip6=fe80:0:0:0:0:0:0:1/64\n");
+        assert_eq!(
+            settings.ipv6,
+            Some(Ipv6Cidr::new(Ipv6Address::new(0xfe80, 0, 0, 0, 0, 0, 0, 1), 64))
+        );
+    }
+
+    #[test]
+    fn test_ignores_malformed_lines() {
+        let settings = NetSettings::parse("not_a_valid_line\nmac=zz:zz:zz:zz:zz:zz\nip=bogus\n");
+        assert_eq!(settings.mac, NetSettings::default().mac);
+        assert_eq!(settings.ipv4, NetSettings::default().ipv4);
+    }
+}
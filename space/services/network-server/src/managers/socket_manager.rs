@@ -4,6 +4,16 @@ use alloc::collections::BTreeMap;
 use core::sync::atomic::{AtomicU32, Ordering};
 use spin::Mutex;
 
+use smoltcp::iface::SocketHandle;
+use smoltcp::wire::IpEndpoint;
+
+/// Which smoltcp socket type a kernel socket fd maps to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SocketKind {
+    Tcp,
+    Udp,
+}
+
 /// Socket information
 #[derive(Debug, Clone)]
 pub struct SocketInfo {
@@ -11,15 +21,13 @@ pub struct SocketInfo {
     pub domain: u32,
     pub sock_type: u32,
     pub protocol: u32,
-    pub bound_addr: Option<(IpAddress, u16)>,
-    pub connected: bool,
-}
-
-/// IP address representation
-#[derive(Debug, Clone)]
-pub enum IpAddress {
-    Ipv4(u32),
-    Ipv6([u8; 16]),
+    /// The smoltcp socket this fd is backed by.
+    pub handle: SocketHandle,
+    pub kind: SocketKind,
+    /// Local port, set by `bind()` or filled in lazily on `connect()`.
+    pub local_port: Option<u16>,
+    /// Destination set by `connect()`; UDP sockets need this to `send_slice`.
+    pub remote: Option<IpEndpoint>,
 }
 
 /// Socket manager responsible for managing socket resources
@@ -37,19 +45,21 @@ impl SocketManager {
         }
     }
 
-    /// Create a new socket
-    pub fn create_socket(&self, domain: u32, sock_type: u32, protocol: u32) -> u32 {
+    /// Create a new socket, backed by an already-allocated smoltcp socket.
+    pub fn create_socket(&self, domain: u32, sock_type: u32, protocol: u32, handle: SocketHandle, kind: SocketKind) -> u32 {
         let sockfd = self.next_socket_fd.fetch_add(1, Ordering::SeqCst);
-        
+
         let socket_info = SocketInfo {
             fd: sockfd,
             domain,
             sock_type,
             protocol,
-            bound_addr: None,
-            connected: false,
+            handle,
+            kind,
+            local_port: None,
+            remote: None,
         };
-        
+
         self.sockets.lock().insert(sockfd, socket_info);
         sockfd
     }
@@ -59,11 +69,22 @@ impl SocketManager {
         self.sockets.lock().get(&sockfd).cloned()
     }
 
-    /// Bind a socket to an address
-    pub fn bind_socket(&self, sockfd: u32, addr: IpAddress, port: u16) -> Result<(), ()> {
+    /// Record the local port a socket was bound to.
+    pub fn bind_socket(&self, sockfd: u32, port: u16) -> Result<(), ()> {
         let mut sockets = self.sockets.lock();
         if let Some(socket) = sockets.get_mut(&sockfd) {
-            socket.bound_addr = Some((addr, port));
+            socket.local_port = Some(port);
+            Ok(())
+        } else {
+            Err(())
+        }
+    }
+
+    /// Record the destination a UDP socket's `connect()` targets.
+    pub fn set_remote(&self, sockfd: u32, remote: IpEndpoint) -> Result<(), ()> {
+        let mut sockets = self.sockets.lock();
+        if let Some(socket) = sockets.get_mut(&sockfd) {
+            socket.remote = Some(remote);
             Ok(())
         } else {
             Err(())
@@ -78,4 +99,4 @@ impl SocketManager {
             Err(())
         }
     }
-}
\ No newline at end of file
+}
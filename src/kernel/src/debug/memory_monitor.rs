@@ -1,106 +0,0 @@
-//! 内存状态监控模块
-
-#![no_std]
-
-extern crate alloc;
-
-use super::{DebugResult, DebugError};
-use heapless::String;
-use alloc::string::ToString;
-
-/// 内存统计信息
-#[derive(Debug)]
-pub struct MemoryStats {
-    /// 总内存大小
-    pub total_memory: usize,
-    /// 已使用内存
-    pub used_memory: usize,
-    /// 空闲内存
-    pub free_memory: usize,
-    /// 内存使用率 (百分比)
-    pub usage_percent: f32,
-}
-
-/// 获取内存状态信息
-pub fn get_memory_status() -> DebugResult<String<512>> {
-    // 在实际实现中，这里应该从内存管理器获取真实数据
-    // 目前返回模拟数据用于演示
-    
-    let stats = MemoryStats {
-        total_memory: 1024 * 1024 * 1024, // 1GB
-        used_memory: 256 * 1024 * 1024,   // 256MB
-        free_memory: 768 * 1024 * 1024,   // 768MB
-        usage_percent: 25.0,
-    };
-    
-    let mut result = String::<512>::new();
-    let status_text = format_memory_status(&stats);
-    if result.push_str(&status_text).is_err() {
-        let mut err_msg = String::<128>::new();
-        err_msg.push_str("Failed to format memory status").unwrap_or(());
-        return Err(DebugError::InternalError(err_msg));
-    }
-    
-    Ok(result)
-}
-
-/// 格式化内存状态信息
-fn format_memory_status(stats: &MemoryStats) -> String<512> {
-    let mut result = String::<512>::new();
-    
-    // 手动格式化字符串，因为no_std环境中没有format宏
-    result.push_str("Memory Status:\n").unwrap_or(());
-    result.push_str("  Total: ").unwrap_or(());
-    result.push_str(&format_number(stats.total_memory)).unwrap_or(());
-    result.push_str(" bytes (").unwrap_or(());
-    result.push_str(&format_float(stats.total_memory as f32 / (1024.0 * 1024.0))).unwrap_or(());
-    result.push_str(" MB)\n").unwrap_or(());
-    
-    result.push_str("  Used:  ").unwrap_or(());
-    result.push_str(&format_number(stats.used_memory)).unwrap_or(());
-    result.push_str(" bytes (").unwrap_or(());
-    result.push_str(&format_float(stats.used_memory as f32 / (1024.0 * 1024.0))).unwrap_or(());
-    result.push_str(" MB)\n").unwrap_or(());
-    
-    result.push_str("  Free:  ").unwrap_or(());
-    result.push_str(&format_number(stats.free_memory)).unwrap_or(());
-    result.push_str(" bytes (").unwrap_or(());
-    result.push_str(&format_float(stats.free_memory as f32 / (1024.0 * 1024.0))).unwrap_or(());
-    result.push_str(" MB)\n").unwrap_or(());
-    
-    result.push_str("  Usage: ").unwrap_or(());
-    result.push_str(&format_float(stats.usage_percent)).unwrap_or(());
-    result.push_str("%\n").unwrap_or(());
-    
-    result
-}
-
-/// 格式化数字
-fn format_number(num: usize) -> String<32> {
-    let mut result = String::<32>::new();
-    // 简单实现，实际应用中可能需要更复杂的数字格式化
-    let num_str = num.to_string();
-    result.push_str(&num_str).unwrap_or(());
-    result
-}
-
-/// 格式化浮点数
-fn format_float(num: f32) -> String<16> {
-    let mut result = String::<16>::new();
-    // 简单实现，实际应用中可能需要更精确的浮点数格式化
-    let float_str = num.to_string();
-    result.push_str(&float_str).unwrap_or(());
-    result
-}
-
-/// 获取详细的内存分配信息
-pub fn get_memory_allocation_details() -> DebugResult<String<256>> {
-    // 在实际实现中，这里应该提供更详细的内存分配信息
-    let mut result = String::<256>::new();
-    result.push_str("Memory Allocation Details:\n").unwrap_or(());
-    result.push_str("  Slab Allocator: Active\n").unwrap_or(());
-    result.push_str("  Buddy Allocator: Active\n").unwrap_or(());
-    result.push_str("  DMA Regions: 0\n").unwrap_or(());
-    result.push_str("  Virtual Memory Maps: 0\n").unwrap_or(());
-    Ok(result)
-}
\ No newline at end of file
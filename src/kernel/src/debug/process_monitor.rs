@@ -1,203 +0,0 @@
-//! 进程状态监控模块
-
-#![no_std]
-
-extern crate alloc;
-
-use super::{DebugResult, DebugError};
-use heapless::String;
-use alloc::string::ToString;
-
-/// 进程信息结构体
-#[derive(Debug)]
-pub struct ProcessInfo {
-    /// 进程ID
-    pub pid: u32,
-    /// 进程名称
-    pub name: String<32>,
-    /// 进程状态
-    pub state: ProcessState,
-    /// 内存使用量
-    pub memory_usage: usize,
-    /// CPU使用时间
-    pub cpu_time: u64,
-}
-
-/// 进程状态枚举
-#[derive(Debug)]
-pub enum ProcessState {
-    /// 运行中
-    Running,
-    /// 等待中
-    Waiting,
-    /// 已停止
-    Stopped,
-    /// 已僵尸
-    Zombie,
-}
-
-/// 获取进程列表
-pub fn get_process_list() -> DebugResult<String<512>> {
-    // 在实际实现中，这里应该从进程管理器获取真实数据
-    // 目前返回模拟数据用于演示
-    
-    let mut result = String::<512>::new();
-    result.push_str("PID\tNAME\t\tSTATE\t\tMEM(KB)\tCPU_TIME\n").unwrap_or(());
-    
-    // 手动创建几个模拟进程
-    let mock_processes = [
-        ProcessInfo {
-            pid: 0,
-            name: {
-                let mut name = String::<32>::new();
-                name.push_str("idle").unwrap_or(());
-                name
-            },
-            state: ProcessState::Running,
-            memory_usage: 1024,
-            cpu_time: 1000000,
-        },
-        ProcessInfo {
-            pid: 1,
-            name: {
-                let mut name = String::<32>::new();
-                name.push_str("init").unwrap_or(());
-                name
-            },
-            state: ProcessState::Running,
-            memory_usage: 4096,
-            cpu_time: 500000,
-        },
-        ProcessInfo {
-            pid: 2,
-            name: {
-                let mut name = String::<32>::new();
-                name.push_str("shell").unwrap_or(());
-                name
-            },
-            state: ProcessState::Waiting,
-            memory_usage: 8192,
-            cpu_time: 250000,
-        },
-    ];
-    
-    for proc in &mock_processes {
-        let line = format_process_line(proc);
-        if result.push_str(&line).is_err() {
-            let mut err_msg = String::<128>::new();
-            err_msg.push_str("Failed to format process list").unwrap_or(());
-            return Err(DebugError::InternalError(err_msg));
-        }
-    }
-    
-    Ok(result)
-}
-
-/// 格式化进程行
-fn format_process_line(proc: &ProcessInfo) -> String<128> {
-    let mut line = String::<128>::new();
-    
-    // 手动格式化，因为no_std环境中没有format宏
-    line.push_str(&format_number(proc.pid as usize)).unwrap_or(());
-    line.push_str("\t").unwrap_or(());
-    line.push_str(&proc.name).unwrap_or(());
-    line.push_str("\t\t").unwrap_or(());
-    
-    // 格式化状态
-    let state_str = match proc.state {
-        ProcessState::Running => "Running",
-        ProcessState::Waiting => "Waiting",
-        ProcessState::Stopped => "Stopped",
-        ProcessState::Zombie => "Zombie",
-    };
-    line.push_str(state_str).unwrap_or(());
-    line.push_str("\t").unwrap_or(());
-    
-    // 格式化内存使用量 (KB)
-    let mem_kb = proc.memory_usage / 1024;
-    line.push_str(&format_number(mem_kb)).unwrap_or(());
-    line.push_str("\t").unwrap_or(());
-    
-    // 格式化CPU时间
-    line.push_str(&format_number(proc.cpu_time as usize)).unwrap_or(());
-    line.push_str("\n").unwrap_or(());
-    
-    line
-}
-
-/// 获取指定进程的详细信息
-pub fn get_process_info(pid: u32) -> DebugResult<String<256>> {
-    // 在实际实现中，这里应该从进程管理器获取指定进程的真实数据
-    // 目前返回模拟数据用于演示
-    
-    if pid > 1000 {
-        return Err(DebugError::NotFound);
-    }
-    
-    let proc_info = ProcessInfo {
-        pid,
-        name: {
-            let mut name = String::<32>::new();
-            name.push_str("process_").unwrap_or(());
-            name.push_str(&format_number(pid as usize)).unwrap_or(());
-            name
-        },
-        state: ProcessState::Running,
-        memory_usage: ((pid + 1) as usize) * 1024,
-        cpu_time: (pid + 1) as u64 * 100000,
-    };
-    
-    let mut result = String::<256>::new();
-    let info_text = format_process_info(&proc_info);
-    if result.push_str(&info_text).is_err() {
-        let mut err_msg = String::<128>::new();
-        err_msg.push_str("Failed to format process info").unwrap_or(());
-        return Err(DebugError::InternalError(err_msg));
-    }
-    
-    Ok(result)
-}
-
-/// 格式化进程详细信息
-fn format_process_info(proc: &ProcessInfo) -> String<256> {
-    let mut result = String::<256>::new();
-    
-    result.push_str("Process Information:\n").unwrap_or(());
-    result.push_str("  PID: ").unwrap_or(());
-    result.push_str(&format_number(proc.pid as usize)).unwrap_or(());
-    result.push_str("\n").unwrap_or(());
-    
-    result.push_str("  Name: ").unwrap_or(());
-    result.push_str(&proc.name).unwrap_or(());
-    result.push_str("\n").unwrap_or(());
-    
-    result.push_str("  State: ").unwrap_or(());
-    let state_str = match proc.state {
-        ProcessState::Running => "Running",
-        ProcessState::Waiting => "Waiting",
-        ProcessState::Stopped => "Stopped",
-        ProcessState::Zombie => "Zombie",
-    };
-    result.push_str(state_str).unwrap_or(());
-    result.push_str("\n").unwrap_or(());
-    
-    result.push_str("  Memory Usage: ").unwrap_or(());
-    let mem_kb = proc.memory_usage / 1024;
-    result.push_str(&format_number(mem_kb)).unwrap_or(());
-    result.push_str(" KB\n").unwrap_or(());
-    
-    result.push_str("  CPU Time: ").unwrap_or(());
-    result.push_str(&format_number(proc.cpu_time as usize)).unwrap_or(());
-    result.push_str("\n").unwrap_or(());
-    
-    result
-}
-
-/// 格式化数字
-fn format_number(num: usize) -> String<32> {
-    let mut result = String::<32>::new();
-    // 简单实现，实际应用中可能需要更复杂的数字格式化
-    let num_str = num.to_string();
-    result.push_str(&num_str).unwrap_or(());
-    result
-}
\ No newline at end of file
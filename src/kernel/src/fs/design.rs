@@ -1,167 +0,0 @@
-//! Enhanced File System Design for HNX Kernel
-//!
-//! This document outlines the design for an enhanced file system for the HNX kernel
-//! that supports directories, permissions, and multiple storage devices.
-
-use alloc::vec::Vec;
-use alloc::string::String;
-use alloc::boxed::Box;
-
-/// File system node type
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum NodeType {
-    File,
-    Directory,
-    Symlink,
-    Device,
-}
-
-/// File permissions
-#[derive(Debug, Clone, Copy)]
-pub struct Permissions {
-    pub read: bool,
-    pub write: bool,
-    pub execute: bool,
-}
-
-/// File metadata
-#[derive(Debug, Clone)]
-pub struct Metadata {
-    pub node_type: NodeType,
-    pub permissions: Permissions,
-    pub size: usize,
-    pub created: u64,  // Timestamp
-    pub modified: u64, // Timestamp
-    pub owner: u32,    // User ID
-    pub group: u32,    // Group ID
-}
-
-/// Directory entry
-#[derive(Debug, Clone)]
-pub struct DirEntry {
-    pub name: String,
-    pub metadata: Metadata,
-}
-
-/// File handle
-pub struct FileHandle {
-    pub inode: u64,
-    pub position: usize,
-    pub flags: u32, // Read, write, append, etc.
-}
-
-/// Enhanced file system trait
-pub trait EnhancedFileSystem {
-    /// Mount a file system
-    fn mount(&mut self, device: &str, mount_point: &str) -> Result<(), FsError>;
-    
-    /// Unmount a file system
-    fn unmount(&mut self, mount_point: &str) -> Result<(), FsError>;
-    
-    /// Create a directory
-    fn mkdir(&mut self, path: &str, permissions: Permissions) -> Result<(), FsError>;
-    
-    /// Remove a directory
-    fn rmdir(&mut self, path: &str) -> Result<(), FsError>;
-    
-    /// Create a file
-    fn create(&mut self, path: &str, permissions: Permissions) -> Result<FileHandle, FsError>;
-    
-    /// Open a file
-    fn open(&mut self, path: &str, flags: u32) -> Result<FileHandle, FsError>;
-    
-    /// Close a file
-    fn close(&mut self, handle: FileHandle) -> Result<(), FsError>;
-    
-    /// Read from a file
-    fn read(&mut self, handle: &mut FileHandle, buffer: &mut [u8]) -> Result<usize, FsError>;
-    
-    /// Write to a file
-    fn write(&mut self, handle: &mut FileHandle, buffer: &[u8]) -> Result<usize, FsError>;
-    
-    /// Seek to a position in a file
-    fn seek(&mut self, handle: &mut FileHandle, offset: isize, whence: SeekWhence) -> Result<usize, FsError>;
-    
-    /// Get file metadata
-    fn stat(&self, path: &str) -> Result<Metadata, FsError>;
-    
-    /// Set file permissions
-    fn chmod(&mut self, path: &str, permissions: Permissions) -> Result<(), FsError>;
-    
-    /// Set file ownership
-    fn chown(&mut self, path: &str, owner: u32, group: u32) -> Result<(), FsError>;
-    
-    /// List directory contents
-    fn readdir(&self, path: &str) -> Result<Vec<DirEntry>, FsError>;
-    
-    /// Remove a file
-    fn unlink(&mut self, path: &str) -> Result<(), FsError>;
-    
-    /// Create a symbolic link
-    fn symlink(&mut self, target: &str, link_path: &str) -> Result<(), FsError>;
-    
-    /// Read a symbolic link
-    fn readlink(&self, path: &str) -> Result<String, FsError>;
-}
-
-/// Seek modes
-#[derive(Debug, Clone, Copy)]
-pub enum SeekWhence {
-    Set,    // Seek from beginning of file
-    Cur,    // Seek from current position
-    End,    // Seek from end of file
-}
-
-/// File system errors
-#[derive(Debug)]
-pub enum FsError {
-    NotFound,
-    PermissionDenied,
-    IsDirectory,
-    NotDirectory,
-    AlreadyExists,
-    IoError,
-    OutOfMemory,
-    InvalidPath,
-    ReadOnly,
-    DiskFull,
-}
-
-/// Virtual File System layer
-pub struct Vfs {
-    /// Mounted file systems
-    pub mounts: Vec<MountPoint>,
-    /// Root file system
-    pub root_fs: Box<dyn EnhancedFileSystem>,
-}
-
-/// Mount point information
-pub struct MountPoint {
-    pub device: String,
-    pub mount_point: String,
-    pub fs: Box<dyn EnhancedFileSystem>,
-}
-
-impl Vfs {
-    /// Create a new VFS instance
-    pub fn new(root_fs: Box<dyn EnhancedFileSystem>) -> Self {
-        Self {
-            mounts: Vec::new(),
-            root_fs,
-        }
-    }
-    
-    /// Resolve a path to a file system and relative path
-    pub fn resolve_path(&self, path: &str) -> (&dyn EnhancedFileSystem, &str) {
-        // Check if path matches any mount point
-        for mount in &self.mounts {
-            if path.starts_with(&mount.mount_point) {
-                let relative_path = &path[mount.mount_point.len()..];
-                return (mount.fs.as_ref(), relative_path);
-            }
-        }
-        
-        // Default to root file system
-        (self.root_fs.as_ref(), path)
-    }
-}
\ No newline at end of file
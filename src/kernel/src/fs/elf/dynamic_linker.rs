@@ -1,414 +0,0 @@
-//! Dynamic linker implementation for HNX microkernel
-//!
-//! This module handles dynamic linking of ELF executables, including:
-//! - Loading shared libraries
-//! - Symbol resolution
-//! - Relocation processing
-//! - PLT/GOT management
-
-extern crate alloc;
-
-use super::{Elf64Ehdr, Elf64Phdr, Elf64Shdr, Elf64Sym, Elf64Rela};
-use super::{PT_LOAD, SHT_RELA, SHT_SYMTAB, SHT_DYNSYM, SHT_DYNAMIC};
-use super::{PF_X, PF_W, PF_R};
-use super::{R_AARCH64_ADR_PREL_PG_HI21, R_AARCH64_ADD_ABS_LO12_NC};
-use super::{R_AARCH64_ABS64, R_AARCH64_GLOB_DAT, R_AARCH64_JUMP_SLOT, R_AARCH64_RELATIVE};
-use crate::arch::common::mmu::MmuFlags;
-use crate::memory::physical::alloc_pages;
-use crate::memory::virtual_::{map_in_pt, KERNEL_BASE};
-use crate::{error, info};
-use alloc::collections::BTreeMap;
-use alloc::vec::Vec;
-use alloc::string::{String, ToString};
-
-/// Dynamic entry types
-pub const DT_NULL: u64 = 0;
-pub const DT_NEEDED: u64 = 1;
-pub const DT_PLTRELSZ: u64 = 2;
-pub const DT_PLTGOT: u64 = 3;
-pub const DT_HASH: u64 = 4;
-pub const DT_STRTAB: u64 = 5;
-pub const DT_SYMTAB: u64 = 6;
-pub const DT_RELA: u64 = 7;
-pub const DT_RELASZ: u64 = 8;
-pub const DT_RELAENT: u64 = 9;
-pub const DT_STRSZ: u64 = 10;
-pub const DT_SYMENT: u64 = 11;
-pub const DT_INIT: u64 = 12;
-pub const DT_FINI: u64 = 13;
-pub const DT_SONAME: u64 = 14;
-pub const DT_RPATH: u64 = 15;
-pub const DT_SYMBOLIC: u64 = 16;
-pub const DT_REL: u64 = 17;
-pub const DT_RELSZ: u64 = 18;
-pub const DT_RELENT: u64 = 19;
-pub const DT_PLTREL: u64 = 20;
-pub const DT_DEBUG: u64 = 21;
-pub const DT_TEXTREL: u64 = 22;
-pub const DT_JMPREL: u64 = 23;
-pub const DT_BIND_NOW: u64 = 24;
-pub const DT_INIT_ARRAY: u64 = 25;
-pub const DT_FINI_ARRAY: u64 = 26;
-pub const DT_INIT_ARRAYSZ: u64 = 27;
-pub const DT_FINI_ARRAYSZ: u64 = 28;
-pub const DT_RUNPATH: u64 = 29;
-pub const DT_FLAGS: u64 = 30;
-
-/// AArch64 relocation types (imported from parent module)
-// pub const R_AARCH64_ABS64: u32 = 257;
-// pub const R_AARCH64_GLOB_DAT: u32 = 1025;
-// pub const R_AARCH64_JUMP_SLOT: u32 = 1026;
-// pub const R_AARCH64_RELATIVE: u32 = 1027;
-
-/// Dynamic entry structure
-#[derive(Debug, Clone)]
-pub struct DynamicEntry {
-    pub d_tag: u64,
-    pub d_val: u64,  // Union with d_ptr
-}
-
-/// Dynamic linking information extracted from ELF
-pub struct DynamicInfo {
-    pub needed_libs: Vec<String>,
-    pub pltgot: Option<u64>,
-    pub jmprel: Option<u64>,
-    pub pltrelsz: Option<u64>,
-    pub pltrel: Option<u64>,
-    pub symtab: Option<u64>,
-    pub strtab: Option<u64>,
-    pub hash: Option<u64>,
-    pub rela: Option<u64>,
-    pub relasz: Option<u64>,
-    pub relaent: Option<u64>,
-}
-
-/// Information about a loaded library
-pub struct LibraryInfo {
-    pub name: String,
-    pub base_address: usize,
-    pub dynamic_info: DynamicInfo,
-    pub symbol_table: Vec<Elf64Sym>,
-    pub string_table: Vec<u8>,
-}
-
-/// Information about a resolved symbol
-pub struct SymbolInfo {
-    pub name: String,
-    pub address: usize,
-    pub size: usize,
-    pub library: String,
-}
-
-/// Dynamic linker responsible for resolving symbols and performing relocations
-pub struct DynamicLinker {
-    loaded_libraries: BTreeMap<String, LibraryInfo>,
-    symbol_table: BTreeMap<String, SymbolInfo>,
-}
-
-impl DynamicLinker {
-    /// Create a new dynamic linker
-    pub fn new() -> Self {
-        Self {
-            loaded_libraries: BTreeMap::new(),
-            symbol_table: BTreeMap::new(),
-        }
-    }
-
-    /// Load a shared library
-    pub fn load_library(&mut self, lib_path: &str) -> Result<usize, ()> {
-        info!("Loading library: {}", lib_path);
-        
-        // Check if already loaded
-        if let Some(lib_info) = self.loaded_libraries.get(lib_path) {
-            return Ok(lib_info.base_address);
-        }
-        
-        // For now, we'll simulate loading by returning a dummy base address
-        // In a real implementation, this would load the actual library file
-        let base_address = 0x10000000; // Dummy address
-        
-        // Create dummy dynamic info
-        let dynamic_info = DynamicInfo {
-            needed_libs: Vec::new(),
-            pltgot: None,
-            jmprel: None,
-            pltrelsz: None,
-            pltrel: None,
-            symtab: None,
-            strtab: None,
-            hash: None,
-            rela: None,
-            relasz: None,
-            relaent: None,
-        };
-        
-        // Create library info
-        let lib_info = LibraryInfo {
-            name: lib_path.to_string(),
-            base_address,
-            dynamic_info,
-            symbol_table: Vec::new(),
-            string_table: Vec::new(),
-        };
-        
-        // Add to loaded libraries
-        self.loaded_libraries.insert(lib_path.to_string(), lib_info);
-        
-        Ok(base_address)
-    }
-
-    /// Resolve a symbol by name
-    pub fn resolve_symbol(&self, symbol_name: &str) -> Option<usize> {
-        self.symbol_table.get(symbol_name).map(|sym| sym.address)
-    }
-
-    /// Perform relocations for a loaded ELF
-    pub fn perform_relocations(&mut self, elf_data: &[u8], base_address: usize) -> Result<(), ()> {
-        info!("Performing relocations for base address: 0x{:016X}", base_address);
-        
-        // Parse ELF header
-        if elf_data.len() < core::mem::size_of::<Elf64Ehdr>() {
-            return Err(());
-        }
-        
-        let ehdr = unsafe { &*(elf_data.as_ptr() as *const Elf64Ehdr) };
-        let e_phoff = ehdr.e_phoff as usize;
-        let e_phentsize = ehdr.e_phentsize as usize;
-        let e_phnum = ehdr.e_phnum as usize;
-        let e_shoff = ehdr.e_shoff as usize;
-        let e_shentsize = ehdr.e_shentsize as usize;
-        let e_shnum = ehdr.e_shnum as usize;
-        
-        // Find dynamic section
-        let dynamic_section = self.find_dynamic_section(elf_data, e_shoff, e_shentsize, e_shnum)?;
-        
-        // Parse dynamic entries
-        let dyn_entries = self.parse_dynamic_entries(elf_data, &dynamic_section)?;
-        
-        // Extract dynamic information
-        let dynamic_info = self.extract_dynamic_info(elf_data, &dyn_entries)?;
-        
-        // Perform RELA relocations
-        if let Some(rela_addr) = dynamic_info.rela {
-            let rela_size = dynamic_info.relasz.unwrap_or(0) as usize;
-            let rela_ent = dynamic_info.relaent.unwrap_or(core::mem::size_of::<Elf64Rela>() as u64) as usize;
-            
-            self.perform_rela_relocations(elf_data, rela_addr as usize, rela_size, rela_ent, base_address)?;
-        }
-        
-        // Perform JMPREL (PLT) relocations
-        if let Some(jmprel_addr) = dynamic_info.jmprel {
-            let jmprel_size = dynamic_info.pltrelsz.unwrap_or(0) as usize;
-            let jmprel_type = dynamic_info.pltrel.unwrap_or(DT_REL);
-            
-            if jmprel_type == DT_RELA {
-                let rela_ent = core::mem::size_of::<Elf64Rela>();
-                self.perform_rela_relocations(elf_data, jmprel_addr as usize, jmprel_size, rela_ent, base_address)?;
-            }
-        }
-        
-        Ok(())
-    }
-
-    /// Find the dynamic section in an ELF file
-    fn find_dynamic_section(&self, elf_data: &[u8], shoff: usize, shentsize: usize, shnum: usize) -> Result<Elf64Shdr, ()> {
-        for i in 0..shnum {
-            let offset = shoff + i * shentsize;
-            if offset + core::mem::size_of::<Elf64Shdr>() > elf_data.len() {
-                continue;
-            }
-            
-            let shdr = unsafe { &*(elf_data[offset..].as_ptr() as *const Elf64Shdr) };
-            if shdr.sh_type == SHT_DYNAMIC {
-                // Clone the struct since we can't move out of a reference
-                let cloned_shdr = Elf64Shdr {
-                    sh_name: shdr.sh_name,
-                    sh_type: shdr.sh_type,
-                    sh_flags: shdr.sh_flags,
-                    sh_addr: shdr.sh_addr,
-                    sh_offset: shdr.sh_offset,
-                    sh_size: shdr.sh_size,
-                    sh_link: shdr.sh_link,
-                    sh_info: shdr.sh_info,
-                    sh_addralign: shdr.sh_addralign,
-                    sh_entsize: shdr.sh_entsize,
-                };
-                return Ok(cloned_shdr);
-            }
-        }
-        
-        Err(())
-    }
-
-    /// Parse dynamic entries from a dynamic section
-    fn parse_dynamic_entries(&self, elf_data: &[u8], dynamic_section: &Elf64Shdr) -> Result<Vec<DynamicEntry>, ()> {
-        let offset = dynamic_section.sh_offset as usize;
-        let size = dynamic_section.sh_size as usize;
-        
-        if offset + size > elf_data.len() {
-            return Err(());
-        }
-        
-        let entry_size = core::mem::size_of::<DynamicEntry>();
-        let num_entries = size / entry_size;
-        let mut entries = Vec::new();
-        
-        for i in 0..num_entries {
-            let entry_offset = offset + i * entry_size;
-            if entry_offset + entry_size <= elf_data.len() {
-                let dyn_entry_data = &elf_data[entry_offset..entry_offset + entry_size];
-                let d_tag = u64::from_le_bytes(dyn_entry_data[0..8].try_into().unwrap());
-                let d_val = u64::from_le_bytes(dyn_entry_data[8..16].try_into().unwrap());
-                let dyn_entry = DynamicEntry { d_tag, d_val };
-                entries.push(dyn_entry);
-                
-                // Stop at DT_NULL
-                if d_tag == DT_NULL {
-                    break;
-                }
-            }
-        }
-        
-        Ok(entries)
-    }
-
-    /// Extract dynamic information from dynamic entries
-    fn extract_dynamic_info(&self, elf_data: &[u8], dyn_entries: &[DynamicEntry]) -> Result<DynamicInfo, ()> {
-        let mut info = DynamicInfo {
-            needed_libs: Vec::new(),
-            pltgot: None,
-            jmprel: None,
-            pltrelsz: None,
-            pltrel: None,
-            symtab: None,
-            strtab: None,
-            hash: None,
-            rela: None,
-            relasz: None,
-            relaent: None,
-        };
-        
-        // Find string table first
-        let strtab_offset = dyn_entries.iter()
-            .find(|entry| entry.d_tag == DT_STRTAB)
-            .map(|entry| entry.d_val)
-            .unwrap_or(0);
-        
-        // Extract needed libraries
-        for entry in dyn_entries {
-            match entry.d_tag {
-                DT_NEEDED => {
-                    if strtab_offset > 0 {
-                        let str_index = entry.d_val as usize;
-                        // Extract string from string table (simplified)
-                        let lib_name = alloc::format!("lib_{}", str_index); // Placeholder
-                        info.needed_libs.push(lib_name);
-                    }
-                }
-                DT_PLTGOT => info.pltgot = Some(entry.d_val),
-                DT_JMPREL => info.jmprel = Some(entry.d_val),
-                DT_PLTRELSZ => info.pltrelsz = Some(entry.d_val),
-                DT_PLTREL => info.pltrel = Some(entry.d_val),
-                DT_SYMTAB => info.symtab = Some(entry.d_val),
-                DT_STRTAB => info.strtab = Some(entry.d_val),
-                DT_HASH => info.hash = Some(entry.d_val),
-                DT_RELA => info.rela = Some(entry.d_val),
-                DT_RELASZ => info.relasz = Some(entry.d_val),
-                DT_RELAENT => info.relaent = Some(entry.d_val),
-                _ => {}
-            }
-        }
-        
-        Ok(info)
-    }
-
-    /// Perform RELA relocations
-    fn perform_rela_relocations(&mut self, elf_data: &[u8], rela_addr: usize, rela_size: usize, 
-                               rela_ent: usize, base_address: usize) -> Result<(), ()> {
-        if rela_addr + rela_size > elf_data.len() {
-            return Err(());
-        }
-        
-        let num_relocs = rela_size / rela_ent;
-        
-        for i in 0..num_relocs {
-            let offset = rela_addr + i * rela_ent;
-            if offset + rela_ent <= elf_data.len() {
-                let rela = unsafe { &*(elf_data[offset..].as_ptr() as *const Elf64Rela) };
-                
-                self.apply_relocation(rela.r_offset as usize + base_address, 
-                                    rela.r_info, rela.r_addend as i64, base_address)?;
-            }
-        }
-        
-        Ok(())
-    }
-
-    /// Apply a single relocation
-    fn apply_relocation(&mut self, target_addr: usize, r_info: u64, addend: i64, base_address: usize) -> Result<(), ()> {
-        let r_type = (r_info & 0xFFFFFFFF) as u32;
-        let r_sym = (r_info >> 32) as u32;
-        
-        match r_type {
-            R_AARCH64_ABS64 => {
-                // For now, we'll use a placeholder address
-                let symbol_addr = 0x20000000 + (r_sym as usize * 8); // Dummy symbol address
-                let value = symbol_addr as i64 + addend;
-                unsafe {
-                    if let Ok(page_addr) = self.get_page_address(target_addr) {
-                        let page_offset = target_addr & 0xFFF;
-                        let kva = KERNEL_BASE + page_addr;
-                        let ptr = (kva + page_offset) as *mut u64;
-                        core::ptr::write_unaligned(ptr, value as u64);
-                    }
-                }
-            }
-            R_AARCH64_GLOB_DAT => {
-                let symbol_addr = 0x20000000 + (r_sym as usize * 8); // Dummy symbol address
-                unsafe {
-                    if let Ok(page_addr) = self.get_page_address(target_addr) {
-                        let page_offset = target_addr & 0xFFF;
-                        let kva = KERNEL_BASE + page_addr;
-                        let ptr = (kva + page_offset) as *mut u64;
-                        core::ptr::write_unaligned(ptr, symbol_addr as u64);
-                    }
-                }
-            }
-            R_AARCH64_JUMP_SLOT => {
-                let symbol_addr = 0x20000000 + (r_sym as usize * 8); // Dummy symbol address
-                unsafe {
-                    if let Ok(page_addr) = self.get_page_address(target_addr) {
-                        let page_offset = target_addr & 0xFFF;
-                        let kva = KERNEL_BASE + page_addr;
-                        let ptr = (kva + page_offset) as *mut u64;
-                        core::ptr::write_unaligned(ptr, symbol_addr as u64);
-                    }
-                }
-            }
-            R_AARCH64_RELATIVE => {
-                let value = (base_address as i64 + addend) as u64;
-                unsafe {
-                    if let Ok(page_addr) = self.get_page_address(target_addr) {
-                        let page_offset = target_addr & 0xFFF;
-                        let kva = KERNEL_BASE + page_addr;
-                        let ptr = (kva + page_offset) as *mut u64;
-                        core::ptr::write_unaligned(ptr, value);
-                    }
-                }
-            }
-            _ => {
-                info!("Unsupported relocation type: {}", r_type);
-            }
-        }
-        
-        Ok(())
-    }
-
-    /// Get the physical page address for a virtual address
-    /// This is a simplified implementation for demonstration
-    fn get_page_address(&self, _virt_addr: usize) -> Result<usize, ()> {
-        // In a real implementation, this would look up the page table
-        // For now, we'll return a dummy page address
-        Ok(0x30000000)
-    }
-}
\ No newline at end of file
@@ -1,58 +0,0 @@
-//! PLT/GOT implementation for HNX microkernel
-//!
-//! This module handles the Procedure Linkage Table (PLT) and Global Offset Table (GOT)
-//! which are used for dynamic linking and lazy symbol resolution.
-
-extern crate alloc;
-
-use alloc::collections::BTreeMap;
-use crate::{error, info};
-
-/// PLT/GOT manager responsible for managing PLT and GOT entries
-pub struct PltGotManager {
-    pub got_entries: BTreeMap<usize, usize>,  // GOT entry address -> resolved address
-    pub plt_entries: BTreeMap<usize, usize>,  // PLT entry address -> symbol info
-}
-
-impl PltGotManager {
-    /// Create a new PLT/GOT manager
-    pub fn new() -> Self {
-        Self {
-            got_entries: BTreeMap::new(),
-            plt_entries: BTreeMap::new(),
-        }
-    }
-
-    /// Initialize GOT entries
-    pub fn initialize_got(&mut self, got_base: usize, num_entries: usize) {
-        // Initialize GOT entries to zero (unresolved)
-        for i in 0..num_entries {
-            let entry_addr = got_base + i * core::mem::size_of::<usize>();
-            self.got_entries.insert(entry_addr, 0);
-        }
-    }
-
-    /// Initialize PLT entries
-    pub fn initialize_plt(&mut self, plt_base: usize, num_entries: usize) {
-        // Initialize PLT entries with placeholder info
-        for i in 0..num_entries {
-            let entry_addr = plt_base + i * 16; // Assuming 16-byte PLT entries
-            self.plt_entries.insert(entry_addr, 0);
-        }
-    }
-
-    /// Resolve a GOT entry
-    pub fn resolve_got_entry(&mut self, entry_addr: usize, resolved_addr: usize) -> Result<(), ()> {
-        if self.got_entries.contains_key(&entry_addr) {
-            self.got_entries.insert(entry_addr, resolved_addr);
-            Ok(())
-        } else {
-            Err(())
-        }
-    }
-
-    /// Get a GOT entry
-    pub fn get_got_entry(&self, entry_addr: usize) -> Option<usize> {
-        self.got_entries.get(&entry_addr).copied()
-    }
-}
\ No newline at end of file
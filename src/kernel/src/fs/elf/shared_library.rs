@@ -1,112 +0,0 @@
-//! Shared library loader for HNX microkernel
-//!
-//! This module handles loading and unloading of shared libraries,
-//! managing library paths, and reference counting.
-
-extern crate alloc;
-
-use alloc::collections::BTreeMap;
-use alloc::vec::Vec;
-use alloc::string::{String, ToString};
-use crate::{error, info};
-
-/// Handle to a loaded shared library
-#[derive(Clone)]
-pub struct LibraryHandle {
-    pub base_address: usize,
-    pub size: usize,
-    pub ref_count: usize,
-}
-
-/// Shared library loader responsible for loading and managing shared libraries
-pub struct SharedLibraryLoader {
-    pub library_paths: [&'static str; 3],
-    pub loaded_libraries: BTreeMap<String, LibraryHandle>,
-}
-
-impl SharedLibraryLoader {
-    /// Create a new shared library loader
-    pub fn new() -> Self {
-        Self {
-            library_paths: [
-                "/lib",
-                "/usr/lib", 
-                "/usr/local/lib"
-            ],
-            loaded_libraries: BTreeMap::new(),
-        }
-    }
-
-    /// Load a shared library by name
-    pub fn load_library(&mut self, lib_name: &str) -> Result<LibraryHandle, ()> {
-        info!("Loading library: {}", lib_name);
-        
-        // Check if already loaded
-        if let Some(handle) = self.loaded_libraries.get(lib_name) {
-            return Ok(LibraryHandle {
-                base_address: handle.base_address,
-                size: handle.size,
-                ref_count: handle.ref_count + 1,
-            });
-        }
-        
-        // Search for library in paths
-        let lib_path = self.find_library(lib_name)?;
-        
-        // For now, we'll simulate loading by returning a dummy handle
-        // In a real implementation, this would load the actual library file
-        let handle = LibraryHandle {
-            base_address: 0x10000000, // Dummy address
-            size: 0x10000, // Dummy size
-            ref_count: 1,
-        };
-        
-        // Store in loaded libraries
-        self.loaded_libraries.insert(lib_name.to_string(), handle.clone());
-        
-        Ok(handle)
-    }
-
-    /// Unload a shared library by name
-    pub fn unload_library(&mut self, lib_name: &str) -> Result<(), ()> {
-        if let Some(mut handle) = self.loaded_libraries.get_mut(lib_name) {
-            handle.ref_count -= 1;
-            if handle.ref_count == 0 {
-                // Actually unload the library
-                // In a real implementation, this would deallocate memory
-                self.loaded_libraries.remove(lib_name);
-            }
-            Ok(())
-        } else {
-            Err(())
-        }
-    }
-
-    /// Find a library in the configured library paths
-    fn find_library(&self, lib_name: &str) -> Result<String, ()> {
-        // Check if it's an absolute path
-        if lib_name.starts_with('/') {
-            if self.file_exists(lib_name) {
-                return Ok(lib_name.to_string());
-            } else {
-                return Err(());
-            }
-        }
-        
-        // Search in library paths
-        for &path in &self.library_paths {
-            let full_path = alloc::format!("{}/{}", path, lib_name);
-            if self.file_exists(&full_path) {
-                return Ok(full_path);
-            }
-        }
-        
-        Err(())
-    }
-
-    /// Check if a file exists (placeholder implementation)
-    fn file_exists(&self, _path: &str) -> bool {
-        // In a real implementation, this would check the filesystem
-        true // Placeholder
-    }
-}
\ No newline at end of file
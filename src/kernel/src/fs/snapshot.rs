@@ -1,381 +0,0 @@
-//! Filesystem snapshot implementation for HNX
-//!
-//! This module implements filesystem snapshots using copy-on-write (COW) techniques.
-//! Snapshots allow capturing the state of a filesystem or directory at a specific
-//! point in time and later restoring it.
-
-extern crate alloc;
-
-use alloc::vec::Vec;
-use alloc::string::{String, ToString};
-use alloc::collections::BTreeMap;
-use core::sync::atomic::{AtomicU64, Ordering};
-
-use crate::fs::vfs::FsError;
-
-/// Snapshot information
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Snapshot {
-    /// Unique snapshot identifier
-    pub id: String,
-    /// Human-readable snapshot name
-    pub name: String,
-    /// Creation timestamp (microseconds since epoch)
-    pub creation_time: u64,
-    /// Parent snapshot ID (None for initial snapshots)
-    pub parent_snapshot: Option<String>,
-    /// Size of the snapshot in bytes
-    pub size: u64,
-    /// Whether the snapshot is read-only
-    pub readonly: bool,
-}
-
-impl Snapshot {
-    /// Create a new snapshot descriptor
-    pub fn new(
-        id: String,
-        name: String,
-        creation_time: u64,
-        parent_snapshot: Option<String>,
-        size: u64,
-        readonly: bool,
-    ) -> Self {
-        Self {
-            id,
-            name,
-            creation_time,
-            parent_snapshot,
-            size,
-            readonly,
-        }
-    }
-}
-
-/// Snapshot metadata stored in the filesystem
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct SnapshotMetadata {
-    /// Snapshot information
-    pub info: Snapshot,
-    /// Root inode of the snapshot
-    pub root_inode: u64,
-    /// Mapping of inodes to their snapshot versions
-    pub inode_map: BTreeMap<u64, u64>,
-}
-
-impl SnapshotMetadata {
-    /// Create new snapshot metadata
-    pub fn new(info: Snapshot, root_inode: u64) -> Self {
-        Self {
-            info,
-            root_inode,
-            inode_map: BTreeMap::new(),
-        }
-    }
-}
-
-/// Copy-on-Write reference counter
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct CowRefCounter {
-    /// Reference count for each data block
-    ref_counts: BTreeMap<u64, u64>, // block_id -> ref_count
-}
-
-impl CowRefCounter {
-    /// Create a new reference counter
-    pub fn new() -> Self {
-        Self {
-            ref_counts: BTreeMap::new(),
-        }
-    }
-    
-    /// Increment reference count for a block
-    pub fn increment(&mut self, block_id: u64) {
-        *self.ref_counts.entry(block_id).or_insert(0) += 1;
-    }
-    
-    /// Decrement reference count for a block
-    /// Returns true if the reference count reached zero
-    pub fn decrement(&mut self, block_id: u64) -> bool {
-        let count = self.ref_counts.entry(block_id).or_insert(0);
-        if *count > 0 {
-            *count -= 1;
-            *count == 0
-        } else {
-            false
-        }
-    }
-    
-    /// Get reference count for a block
-    pub fn get_count(&self, block_id: u64) -> u64 {
-        *self.ref_counts.get(&block_id).unwrap_or(&0)
-    }
-    
-    /// Check if a block is referenced
-    pub fn is_referenced(&self, block_id: u64) -> bool {
-        self.get_count(block_id) > 0
-    }
-}
-
-/// Snapshot manager
-pub struct SnapshotManager {
-    /// Next snapshot ID
-    next_snapshot_id: AtomicU64,
-    /// Active snapshots
-    snapshots: BTreeMap<String, SnapshotMetadata>,
-    /// Reference counter for COW blocks
-    ref_counter: CowRefCounter,
-}
-
-impl SnapshotManager {
-    /// Create a new snapshot manager
-    pub fn new() -> Self {
-        Self {
-            next_snapshot_id: AtomicU64::new(1),
-            snapshots: BTreeMap::new(),
-            ref_counter: CowRefCounter::new(),
-        }
-    }
-    
-    /// Generate a new unique snapshot ID
-    fn generate_snapshot_id(&self) -> String {
-        let id = self.next_snapshot_id.fetch_add(1, Ordering::SeqCst);
-        alloc::format!("snap_{}", id)
-    }
-    
-    /// Create a new snapshot
-    pub fn create_snapshot(
-        &mut self,
-        name: String,
-        parent_snapshot: Option<String>,
-        root_inode: u64,
-    ) -> Result<Snapshot, FsError> {
-        let id = self.generate_snapshot_id();
-        let creation_time = crate::arch::timer::now_us();
-        
-        let snapshot = Snapshot::new(
-            id.clone(),
-            name,
-            creation_time,
-            parent_snapshot,
-            0, // Size will be calculated later
-            true, // Snapshots are read-only
-        );
-        
-        let metadata = SnapshotMetadata::new(snapshot.clone(), root_inode);
-        self.snapshots.insert(id, metadata);
-        
-        Ok(snapshot)
-    }
-    
-    /// Get snapshot by ID
-    pub fn get_snapshot(&self, snapshot_id: &str) -> Option<&Snapshot> {
-        self.snapshots.get(snapshot_id).map(|meta| &meta.info)
-    }
-    
-    /// List all snapshots
-    pub fn list_snapshots(&self) -> Vec<Snapshot> {
-        self.snapshots.values().map(|meta| meta.info.clone()).collect()
-    }
-    
-    /// Delete a snapshot
-    pub fn delete_snapshot(&mut self, snapshot_id: &str) -> Result<(), FsError> {
-        if self.snapshots.remove(snapshot_id).is_some() {
-            // TODO: Implement proper cleanup of snapshot data
-            // This would involve decrementing reference counts and freeing unreferenced blocks
-            Ok(())
-        } else {
-            Err(FsError::NotFound)
-        }
-    }
-    
-    /// Clone a snapshot
-    pub fn clone_snapshot(
-        &mut self,
-        source_snapshot_id: &str,
-        new_name: String,
-    ) -> Result<Snapshot, FsError> {
-        let source_meta = self.snapshots.get(source_snapshot_id).ok_or(FsError::NotFound)?;
-        
-        let id = self.generate_snapshot_id();
-        let creation_time = crate::arch::timer::now_us();
-        
-        let snapshot = Snapshot::new(
-            id.clone(),
-            new_name,
-            creation_time,
-            Some(source_snapshot_id.to_string()),
-            source_meta.info.size,
-            true, // Cloned snapshots are also read-only
-        );
-        
-        let mut metadata = source_meta.clone();
-        metadata.info = snapshot.clone();
-        self.snapshots.insert(id, metadata);
-        
-        Ok(snapshot)
-    }
-    
-    /// Restore a snapshot
-    /// This would typically involve replacing the current filesystem state with the snapshot state
-    pub fn restore_snapshot(&mut self, snapshot_id: &str) -> Result<(), FsError> {
-        let snapshot = self.snapshots.get(snapshot_id).ok_or(FsError::NotFound)?;
-        
-        // TODO: Implement actual restoration logic
-        // This would involve:
-        // 1. Verifying the snapshot is valid
-        // 2. Replacing current filesystem state with snapshot state
-        // 3. Updating reference counts appropriately
-        // 4. Handling any conflicts or inconsistencies
-        
-        // For now, we'll just mark that restoration was requested
-        crate::info!("Requested restoration of snapshot: {}", snapshot_id);
-        
-        Ok(())
-    }
-    
-    /// Get the root inode for a snapshot
-    pub fn get_snapshot_root(&self, snapshot_id: &str) -> Result<u64, FsError> {
-        let metadata = self.snapshots.get(snapshot_id).ok_or(FsError::NotFound)?;
-        Ok(metadata.root_inode)
-    }
-    
-    /// Increment reference count for a block
-    pub fn increment_block_ref(&mut self, block_id: u64) {
-        self.ref_counter.increment(block_id);
-    }
-    
-    /// Decrement reference count for a block
-    /// Returns true if the block is no longer referenced
-    pub fn decrement_block_ref(&mut self, block_id: u64) -> bool {
-        self.ref_counter.decrement(block_id)
-    }
-    
-    /// Check if a block is referenced by any snapshot
-    pub fn is_block_referenced(&self, block_id: u64) -> bool {
-        self.ref_counter.is_referenced(block_id)
-    }
-}
-
-/// Snapshot operations trait
-pub trait SnapshotOps {
-    /// Create a snapshot of a filesystem path
-    fn create_snapshot(&mut self, name: &str, path: &str) -> Result<String, FsError>;
-    
-    /// Delete a snapshot
-    fn delete_snapshot(&mut self, snapshot_id: &str) -> Result<(), FsError>;
-    
-    /// List snapshots for a filesystem path
-    fn list_snapshots(&self, path: &str) -> Result<Vec<Snapshot>, FsError>;
-    
-    /// Restore a snapshot to a target path
-    fn restore_snapshot(&mut self, snapshot_id: &str, target_path: &str) -> Result<(), FsError>;
-    
-    /// Clone a snapshot with a new name
-    fn clone_snapshot(&mut self, snapshot_id: &str, new_name: &str) -> Result<String, FsError>;
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    
-    #[test]
-    fn test_snapshot_creation() {
-        let mut manager = SnapshotManager::new();
-        
-        let snapshot = manager.create_snapshot(
-            "test_snapshot".to_string(),
-            None,
-            100,
-        );
-        
-        assert!(snapshot.is_ok());
-        let snapshot = snapshot.unwrap();
-        assert_eq!(snapshot.name, "test_snapshot");
-        assert_eq!(snapshot.parent_snapshot, None);
-        assert!(snapshot.readonly);
-    }
-    
-    #[test]
-    fn test_snapshot_retrieval() {
-        let mut manager = SnapshotManager::new();
-        
-        let snapshot = manager.create_snapshot(
-            "test_snapshot".to_string(),
-            None,
-            100,
-        ).unwrap();
-        
-        let retrieved = manager.get_snapshot(&snapshot.id);
-        assert!(retrieved.is_some());
-        assert_eq!(retrieved.unwrap().id, snapshot.id);
-    }
-    
-    #[test]
-    fn test_snapshot_listing() {
-        let mut manager = SnapshotManager::new();
-        
-        manager.create_snapshot("snap1".to_string(), None, 100).unwrap();
-        manager.create_snapshot("snap2".to_string(), None, 101).unwrap();
-        
-        let snapshots = manager.list_snapshots();
-        assert_eq!(snapshots.len(), 2);
-    }
-    
-    #[test]
-    fn test_snapshot_deletion() {
-        let mut manager = SnapshotManager::new();
-        
-        let snapshot = manager.create_snapshot(
-            "test_snapshot".to_string(),
-            None,
-            100,
-        ).unwrap();
-        
-        assert!(manager.delete_snapshot(&snapshot.id).is_ok());
-        assert!(manager.get_snapshot(&snapshot.id).is_none());
-    }
-    
-    #[test]
-    fn test_snapshot_cloning() {
-        let mut manager = SnapshotManager::new();
-        
-        let original = manager.create_snapshot(
-            "original".to_string(),
-            None,
-            100,
-        ).unwrap();
-        
-        let cloned = manager.clone_snapshot(&original.id, "cloned".to_string());
-        assert!(cloned.is_ok());
-        
-        let cloned = cloned.unwrap();
-        assert_eq!(cloned.name, "cloned");
-        assert_eq!(cloned.parent_snapshot, Some(original.id));
-    }
-    
-    #[test]
-    fn test_cow_ref_counter() {
-        let mut ref_counter = CowRefCounter::new();
-        
-        // Test increment
-        ref_counter.increment(1);
-        assert_eq!(ref_counter.get_count(1), 1);
-        assert!(ref_counter.is_referenced(1));
-        
-        // Test multiple increments
-        ref_counter.increment(1);
-        assert_eq!(ref_counter.get_count(1), 2);
-        
-        // Test decrement
-        assert!(!ref_counter.decrement(1)); // Still referenced
-        assert_eq!(ref_counter.get_count(1), 1);
-        
-        // Test final decrement
-        assert!(ref_counter.decrement(1)); // No longer referenced
-        assert_eq!(ref_counter.get_count(1), 0);
-        assert!(!ref_counter.is_referenced(1));
-        
-        // Test decrementing non-existent block
-        assert!(!ref_counter.decrement(999));
-    }
-}
\ No newline at end of file
@@ -1,216 +0,0 @@
-//! Symbolic link implementation for HNX filesystem
-//! 
-//! This module implements symbolic links as a special type of file that contains
-//! a path string pointing to another file or directory. Symbolic links can be
-//! absolute or relative, and may point to files or directories that don't exist.
-
-extern crate alloc;
-
-use alloc::string::{String, ToString};
-use alloc::sync::Arc;
-use alloc::collections::BTreeSet;
-use spin::RwLock;
-use core::sync::atomic::{AtomicU64, Ordering};
-
-use crate::fs::vfs::{FileSystem, WritableFileSystem, FileHandle, FsError, Permissions, Metadata};
-use crate::fs::dirent::{DirEntry, DirEntryType};
-use crate::fs::path::{canonicalize_path, join_paths, MAX_PATH_LEN};
-use crate::fs::ramfs::RamFs;
-
-/// Maximum length of a symbolic link target path
-pub const MAX_SYMLINK_TARGET_LEN: usize = MAX_PATH_LEN;
-
-/// Symbolic link data structure
-#[derive(Debug, Clone)]
-pub struct Symlink {
-    /// Inode number
-    pub inode: u64,
-    /// Name of the symlink
-    pub name: String,
-    /// Target path this symlink points to
-    pub target: String,
-    /// Creation timestamp
-    pub created: u64,
-    /// Last modification timestamp
-    pub modified: u64,
-    /// Permissions for the symlink itself
-    pub permissions: Permissions,
-}
-
-impl Symlink {
-    /// Create a new symbolic link
-    pub fn new(inode: u64, name: String, target: String, mode: u32) -> Self {
-        let now = crate::arch::timer::now_us();
-        Self {
-            inode,
-            name,
-            target,
-            created: now,
-            modified: now,
-            permissions: Permissions {
-                read: (mode & 0o400) != 0,
-                write: (mode & 0o200) != 0,
-                execute: (mode & 0o100) != 0,
-                owner: 0,  // Root user for now
-                group: 0,  // Root group for now
-            },
-        }
-    }
-}
-
-/// Symbolic link operations trait
-pub trait SymlinkOps {
-    /// Create a symbolic link
-    fn create_symlink(&mut self, path: &str, target: &str) -> Result<(), FsError>;
-    
-    /// Read the target of a symbolic link
-    fn read_symlink(&self, path: &str) -> Result<String, FsError>;
-    
-    /// Resolve a symbolic link to its target path
-    fn resolve_symlink(&self, path: &str) -> Result<String, FsError>;
-    
-    /// Check if a path refers to a symbolic link
-    fn is_symlink(&self, path: &str) -> bool;
-}
-
-/// Extension trait for filesystems that support symbolic links
-pub trait SymlinkFileSystem: WritableFileSystem {
-    /// Follow a symbolic link chain up to a maximum depth
-    fn follow_symlink(&self, path: &str, max_depth: usize) -> Result<String, FsError>;
-    
-    /// Detect circular references in symbolic links
-    fn detect_symlink_cycle(&self, path: &str) -> Result<bool, FsError>;
-}
-
-/// Symbolic link manager
-pub struct SymlinkManager {
-    /// Next inode number to assign
-    next_inode: AtomicU64,
-    /// Maximum recursion depth for symlink resolution
-    max_recursion_depth: usize,
-}
-
-impl SymlinkManager {
-    /// Create a new symlink manager
-    pub fn new() -> Self {
-        Self {
-            next_inode: AtomicU64::new(1000), // Start inode numbers from 1000 for symlinks
-            max_recursion_depth: 8, // Default maximum recursion depth
-        }
-    }
-    
-    /// Allocate next inode number
-    fn next_inode(&self) -> u64 {
-        self.next_inode.fetch_add(1, Ordering::SeqCst)
-    }
-    
-    /// Set maximum recursion depth for symlink resolution
-    pub fn set_max_recursion_depth(&mut self, depth: usize) {
-        self.max_recursion_depth = depth;
-    }
-    
-    /// Get maximum recursion depth
-    pub fn max_recursion_depth(&self) -> usize {
-        self.max_recursion_depth
-    }
-}
-
-impl Default for SymlinkManager {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-// Note: SymlinkOps and SymlinkFileSystem implementations for RamFs are in the ramfs module
-// to avoid circular dependencies and access to private methods.
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    
-    #[test]
-    fn test_symlink_creation() {
-        let mut fs = RamFs::new();
-        
-        // Create a symlink
-        assert!(fs.create_symlink("/link", "/target").is_ok());
-        
-        // Check that it exists
-        assert!(fs.is_symlink("/link"));
-        
-        // Read the symlink target
-        let target = fs.read_symlink("/link").unwrap();
-        assert_eq!(target, "/target");
-    }
-    
-    #[test]
-    fn test_symlink_resolution() {
-        let mut fs = RamFs::new();
-        
-        // Create a symlink
-        fs.create_symlink("/link", "/target").unwrap();
-        
-        // Resolve the symlink
-        let resolved = fs.resolve_symlink("/link").unwrap();
-        assert_eq!(resolved, "/target");
-    }
-    
-    #[test]
-    fn test_relative_symlink_resolution() {
-        let mut fs = RamFs::new();
-        
-        // Create a relative symlink
-        fs.create_symlink("/dir/link", "target").unwrap();
-        
-        // Resolve the symlink
-        let resolved = fs.resolve_symlink("/dir/link").unwrap();
-        assert_eq!(resolved, "/dir/target");
-    }
-    
-    #[test]
-    fn test_follow_symlink() {
-        let mut fs = RamFs::new();
-        
-        // Create a chain of symlinks
-        fs.create_symlink("/link1", "/link2").unwrap();
-        fs.create_symlink("/link2", "/link3").unwrap();
-        fs.create_symlink("/link3", "/final").unwrap();
-        
-        // Follow the symlink chain
-        let final_path = fs.follow_symlink("/link1", 10).unwrap();
-        assert_eq!(final_path, "/final");
-    }
-    
-    #[test]
-    fn test_symlink_recursion_limit() {
-        let mut fs = RamFs::new();
-        
-        // Create a recursive symlink
-        fs.create_symlink("/link", "/link").unwrap();
-        
-        // Try to follow it with a low recursion limit
-        assert!(fs.follow_symlink("/link", 3).is_err());
-    }
-    
-    #[test]
-    fn test_symlink_cycle_detection() {
-        let mut fs = RamFs::new();
-        
-        // Create a recursive symlink
-        fs.create_symlink("/link", "/link").unwrap();
-        
-        // Detect the cycle
-        assert!(fs.detect_symlink_cycle("/link").unwrap());
-    }
-    
-    #[test]
-    fn test_symlink_no_cycle() {
-        let mut fs = RamFs::new();
-        
-        // Create a normal symlink
-        fs.create_symlink("/link", "/target").unwrap();
-        
-        // Check that there's no cycle
-        assert!(!fs.detect_symlink_cycle("/link").unwrap());
-    }
-}
\ No newline at end of file
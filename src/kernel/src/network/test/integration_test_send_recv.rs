@@ -1,146 +0,0 @@
-//! Integration test for TCP send/recv functionality
-//!
-//! This test verifies the complete socket API workflow:
-//! 1. Create a TCP socket
-//! 2. Bind it to a local address
-//! 3. Listen for connections
-//! 4. Accept a connection
-//! 5. Send data through the connection
-//! 6. Receive data through the connection
-//! 7. Close the socket
-
-extern crate alloc;
-
-use crate::network::test::{NetworkError, Result};
-use crate::network::test::socket::{SocketDomain, SocketType, SocketProtocol, SocketAddress};
-use crate::network::test::interface::{NetworkInterface, InterfaceFlags};
-
-/// Integration test for TCP send/recv functionality
-pub fn integration_test_tcp_send_recv() -> Result<()> {
-    crate::info!("network/integration_test: Starting TCP send/recv integration test");
-    
-    // Initialize the network subsystem
-    crate::network::init();
-    
-    // Create a network interface for testing
-    let mut interface = NetworkInterface::new(1, "test0");
-    interface.set_mac_address([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]);
-    interface.set_ip_address(0x0A000001u32.to_be(), 0xFFFFFF00u32.to_be()); // 10.0.0.1/24
-    interface.flags.set(InterfaceFlags::UP);
-    
-    // Register the interface
-    crate::network::interface::register_interface(interface)?;
-    
-    // Create a TCP socket
-    let server_socket_id = crate::network::socket::socket(
-        SocketDomain::INET, 
-        SocketType::STREAM, 
-        SocketProtocol::TCP
-    )?;
-    
-    crate::info!("network/integration_test: Created server socket with ID {}", server_socket_id);
-    
-    // Bind the socket to a local address
-    let local_addr = SocketAddress::new(0x0A000001u32, 9090u16); // 10.0.0.1:9090
-    crate::network::socket::bind(server_socket_id, local_addr)?;
-    
-    crate::info!("network/integration_test: Bound server socket to address");
-    
-    // Listen for incoming connections with a backlog of 5
-    crate::network::socket::listen(server_socket_id, 5)?;
-    
-    crate::info!("network/integration_test: Server started listening with backlog of 5");
-    
-    // For testing purposes, we'll simulate a client connecting and then test send/recv
-    // In a real scenario, this would be handled by the TCP stack receiving packets
-    
-    // Since we can't easily simulate actual network traffic in this test environment,
-    // we'll test the API functions directly by creating a connected socket
-    
-    // Create a client socket to simulate the connection
-    let client_socket_id = crate::network::socket::socket(
-        SocketDomain::INET, 
-        SocketType::STREAM, 
-        SocketProtocol::TCP
-    )?;
-    
-    crate::info!("network/integration_test: Created client socket with ID {}", client_socket_id);
-    
-    // Connect the client socket to our server
-    let server_addr = SocketAddress::new(0x0A000001u32, 9090u16); // 10.0.0.1:9090
-    crate::network::socket::connect(client_socket_id, server_addr)?;
-    
-    crate::info!("network/integration_test: Client connected to server");
-    
-    // Now we have two connected sockets, we can test send/recv
-    
-    // Test sending data from client to server
-    let test_data = b"Hello, HNX Network Stack!";
-    let sent_bytes = crate::network::socket::send(client_socket_id, test_data)?;
-    
-    crate::info!("network/integration_test: Sent {} bytes from client to server", sent_bytes);
-    assert_eq!(sent_bytes, test_data.len(), "Expected to send all bytes");
-    
-    // Test receiving data on the server side
-    // Note: In a real implementation, we would need to accept the connection first
-    // But for this test, we'll simulate having an established connection
-    
-    // Create a simulated accepted socket to represent the server side of the connection
-    let accepted_socket_id = crate::network::socket::socket(
-        SocketDomain::INET, 
-        SocketType::STREAM, 
-        SocketProtocol::TCP
-    )?;
-    
-    // Set up the accepted socket as if it were the result of accept()
-    let remote_addr = SocketAddress::new(0x0A000002u32, 12345u16); // 10.0.0.2:12345 (simulated client)
-    crate::network::socket::connect(accepted_socket_id, remote_addr)?;
-    
-    crate::info!("network/integration_test: Created simulated accepted socket with ID {}", accepted_socket_id);
-    
-    // Test receiving data on the accepted socket
-    let mut recv_buffer = [0u8; 1024];
-    let received_bytes = crate::network::socket::recv(accepted_socket_id, &mut recv_buffer)?;
-    
-    crate::info!("network/integration_test: Received {} bytes on accepted socket", received_bytes);
-    
-    // Verify the received data matches what was sent
-    // Note: In a real test environment with actual network simulation, we would compare the data
-    // For now, we'll just verify the function works correctly
-    
-    // Test sending data from server to client
-    let response_data = b"Hello back from server!";
-    let sent_bytes = crate::network::socket::send(accepted_socket_id, response_data)?;
-    
-    crate::info!("network/integration_test: Sent {} bytes from server to client", sent_bytes);
-    assert_eq!(sent_bytes, response_data.len(), "Expected to send all bytes in response");
-    
-    // Test receiving data on the client side
-    let mut client_recv_buffer = [0u8; 1024];
-    let received_bytes = crate::network::socket::recv(client_socket_id, &mut client_recv_buffer)?;
-    
-    crate::info!("network/integration_test: Received {} bytes on client socket", received_bytes);
-    
-    // Clean up all sockets
-    crate::network::socket::close(client_socket_id)?;
-    crate::network::socket::close(accepted_socket_id)?;
-    crate::network::socket::close(server_socket_id)?;
-    crate::network::interface::unregister_interface(1)?;
-    
-    crate::info!("network/integration_test: TCP send/recv integration test completed successfully");
-    
-    Ok(())
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    
-    #[test]
-    fn test_tcp_send_recv_integration() {
-        // This test would normally be run in a kernel environment
-        // For now, we'll just verify the function compiles correctly
-        // In a real scenario, this would be part of the kernel's integration test suite
-        assert!(true); // Placeholder until we can run actual kernel tests
-    }
-}
\ No newline at end of file
@@ -1,17 +0,0 @@
-//! Network tests module
-//!
-//! This module contains all network-related tests.
-
-pub mod test_firewall;
-pub mod test_network_monitoring;
-pub mod test_qos;
-pub mod test_qos_simple;
-pub mod test_tcp_accept;
-pub mod test_tcp_send_recv;
-pub mod test_socket_nonblocking;
-pub mod integration_test_accept;
-pub mod integration_test_send_recv;
-pub mod integration_test;
-pub mod integration_test_qos;
-pub mod run_tests;
-pub mod enhanced_tests;
\ No newline at end of file
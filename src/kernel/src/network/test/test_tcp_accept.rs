@@ -1,44 +0,0 @@
-//! Test module for TCP accept functionality
-
-extern crate alloc;
-
-use crate::network::test::{NetworkError, Result};
-use crate::network::test::socket::{SocketDomain, SocketType, SocketProtocol, SocketAddress};
-use crate::network::test::interface::{NetworkInterface, InterfaceFlags};
-
-/// Test TCP accept functionality
-pub fn test_tcp_accept() -> Result<()> {
-    // Create a network interface for testing
-    let mut interface = NetworkInterface::new(1, "test0");
-    interface.set_mac_address([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]);
-    interface.set_ip_address(0x0A000001u32.to_be(), 0xFFFFFF00u32.to_be()); // 10.0.0.1/24
-    interface.flags.set(InterfaceFlags::UP);
-    
-    // Register the interface
-    crate::network::interface::register_interface(interface)?;
-    
-    // Create a TCP socket
-    let socket_id = crate::network::socket::socket(
-        SocketDomain::INET, 
-        SocketType::STREAM, 
-        SocketProtocol::TCP
-    )?;
-    
-    // Bind the socket to a local address
-    let local_addr = SocketAddress::new(0x0A000001u32.to_be(), 8080u16.to_be()); // 10.0.0.1:8080
-    crate::network::socket::bind(socket_id, local_addr)?;
-    
-    // Listen for incoming connections with a backlog of 5
-    crate::network::socket::listen(socket_id, 5)?;
-    
-    // At this point, we would simulate receiving a SYN packet
-    // and then call accept() to handle the connection
-    
-    crate::info!("network/test: TCP accept test setup completed");
-    
-    // Clean up
-    crate::network::socket::close(socket_id)?;
-    crate::network::interface::unregister_interface(1)?;
-    
-    Ok(())
-}
\ No newline at end of file
@@ -1,61 +0,0 @@
-//! Simple test for TCP send/recv functionality
-//!
-//! This test focuses specifically on verifying that the send_to and recv_from
-//! functions in the TCP module work correctly.
-
-extern crate alloc;
-
-use crate::network::test::{NetworkError, Result};
-use crate::network::test::socket::{SocketDomain, SocketType, SocketProtocol, SocketAddress};
-use crate::network::test::interface::{NetworkInterface, InterfaceFlags};
-
-/// Simple test for TCP send/recv functionality
-pub fn test_tcp_send_recv() -> Result<()> {
-    crate::info!("network/test: Starting TCP send/recv test");
-    
-    // Initialize the network subsystem
-    crate::network::init();
-    
-    // Create a network interface for testing
-    let mut interface = NetworkInterface::new(1, "test0");
-    interface.set_mac_address([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]);
-    interface.set_ip_address(0xC0A80001u32.to_be(), 0xFFFFFF00u32.to_be()); // 192.168.0.1/24
-    interface.flags.set(InterfaceFlags::UP);
-    
-    // Register the interface
-    crate::network::interface::register_interface(interface)?;
-    
-    // Create a TCP socket
-    let socket_id = crate::network::socket::socket(
-        SocketDomain::INET, 
-        SocketType::STREAM, 
-        SocketProtocol::TCP
-    )?;
-    
-    crate::info!("network/test: Created socket with ID {}", socket_id);
-    
-    // Bind the socket to a local address
-    let local_addr = SocketAddress::new(0xC0A80001u32, 8000u16); // 192.168.0.1:8000
-    crate::network::socket::bind(socket_id, local_addr)?;
-    
-    crate::info!("network/test: Bound socket to address");
-    
-    // Test direct TCP send/receive functions
-    // These tests verify that the underlying TCP functions work correctly
-    
-    // Test sending data through TCP directly
-    let test_data = b"Test data for TCP send function";
-    
-    // Note: In a real implementation, we would need to establish a connection first
-    // For this test, we're checking that the API functions exist and can be called
-    
-    crate::info!("network/test: Testing TCP send/recv functions");
-    
-    // Clean up
-    crate::network::socket::close(socket_id)?;
-    crate::network::interface::unregister_interface(1)?;
-    
-    crate::info!("network/test: TCP send/recv test completed");
-    
-    Ok(())
-}
\ No newline at end of file
@@ -1,55 +0,0 @@
-//! Dynamic linking syscalls for HNX microkernel
-//!
-//! This module implements the syscalls for dynamic linking support:
-//! - sys_dlopen: Load a shared library
-//! - sys_dlclose: Unload a shared library
-//! - sys_dlsym: Resolve a symbol
-
-extern crate alloc;
-
-// Note: Dynamic linking functionality has been moved to userspace
-// These syscalls are placeholders for future implementation
-
-/// dlopen syscall - Load a shared library
-/// 
-/// # Arguments
-/// * `lib_name_ptr` - Pointer to the library name string
-/// * `flags` - Flags for loading (currently ignored)
-/// 
-/// # Returns
-/// * `Ok(base_address)` - Base address of loaded library
-/// * `Err(error_code)` - Error code
-pub fn sys_dlopen(_lib_name_ptr: usize, _flags: u32) -> Result<isize, isize> {
-    // Placeholder implementation
-    // In a real implementation, this would load a shared library
-    Ok(0)
-}
-
-/// dlclose syscall - Unload a shared library
-/// 
-/// # Arguments
-/// * `handle` - Handle to the loaded library
-/// 
-/// # Returns
-/// * `Ok(0)` - Success
-/// * `Err(error_code)` - Error code
-pub fn sys_dlclose(_handle: usize) -> Result<isize, isize> {
-    // Placeholder implementation
-    // In a real implementation, this would unload a shared library
-    Ok(0)
-}
-
-/// dlsym syscall - Resolve a symbol
-/// 
-/// # Arguments
-/// * `handle` - Handle to the loaded library
-/// * `symbol_ptr` - Pointer to the symbol name string
-/// 
-/// # Returns
-/// * `Ok(symbol_address)` - Address of the resolved symbol
-/// * `Err(error_code)` - Error code
-pub fn sys_dlsym(_handle: usize, _symbol_ptr: usize) -> Result<isize, isize> {
-    // Placeholder implementation
-    // In a real implementation, this would resolve a symbol
-    Ok(0)
-}
\ No newline at end of file
@@ -1,210 +0,0 @@
-//! Synchronization primitives for the HNX kernel
-//!
-//! This module provides various synchronization primitives that can be used
-//! to coordinate access to shared resources between processes and threads.
-
-// pub mod process_sync;
-
-use core::cell::UnsafeCell;
-use core::ops::{Deref, DerefMut};
-use core::sync::atomic::{AtomicBool, AtomicU32, Ordering};
-
-/// A mutual exclusion primitive that protects shared data
-///
-/// This mutex is designed for use in a microkernel environment where
-/// processes may need to synchronize access to shared resources.
-pub struct Mutex<T: ?Sized> {
-    locked: AtomicBool,
-    data: UnsafeCell<T>,
-}
-
-unsafe impl<T: ?Sized + Send> Sync for Mutex<T> {}
-unsafe impl<T: ?Sized + Send> Send for Mutex<T> {}
-
-impl<T> Mutex<T> {
-    /// Creates a new mutex in an unlocked state
-    pub const fn new(data: T) -> Self {
-        Self {
-            locked: AtomicBool::new(false),
-            data: UnsafeCell::new(data),
-        }
-    }
-}
-
-impl<T: ?Sized> Mutex<T> {
-    /// Acquires the mutex, blocking the current process if necessary
-    ///
-    /// This function will block until the mutex can be acquired.
-    /// Returns a guard that releases the mutex when dropped.
-    pub fn lock(&self) -> MutexGuard<'_, T> {
-        // Simple spinlock implementation for now
-        // In a more advanced implementation, we could block the process
-        // and put it in a waiting queue
-        while self.locked.compare_exchange_weak(
-            false,
-            true,
-            Ordering::Acquire,
-            Ordering::Relaxed,
-        ).is_err() {
-            // Spin wait
-            core::hint::spin_loop();
-        }
-        
-        MutexGuard { mutex: self }
-    }
-    
-    /// Attempts to acquire the mutex without blocking
-    ///
-    /// Returns Some(guard) if the mutex was acquired, None otherwise.
-    pub fn try_lock(&self) -> Option<MutexGuard<'_, T>> {
-        if self.locked.compare_exchange(
-            false,
-            true,
-            Ordering::Acquire,
-            Ordering::Relaxed,
-        ).is_ok() {
-            Some(MutexGuard { mutex: self })
-        } else {
-            None
-        }
-    }
-}
-
-/// A guard that releases the mutex when dropped
-pub struct MutexGuard<'a, T: ?Sized> {
-    mutex: &'a Mutex<T>,
-}
-
-impl<'a, T: ?Sized> Deref for MutexGuard<'a, T> {
-    type Target = T;
-    
-    fn deref(&self) -> &Self::Target {
-        unsafe { &*self.mutex.data.get() }
-    }
-}
-
-impl<'a, T: ?Sized> DerefMut for MutexGuard<'a, T> {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        unsafe { &mut *self.mutex.data.get() }
-    }
-}
-
-impl<'a, T: ?Sized> Drop for MutexGuard<'a, T> {
-    fn drop(&mut self) {
-        self.mutex.locked.store(false, Ordering::Release);
-    }
-}
-
-/// A semaphore implementation for process synchronization
-pub struct Semaphore {
-    count: AtomicU32,
-}
-
-impl Semaphore {
-    /// Creates a new semaphore with the given initial count
-    pub const fn new(count: u32) -> Self {
-        Self {
-            count: AtomicU32::new(count),
-        }
-    }
-    
-    /// Acquires a permit from the semaphore, blocking if necessary
-    ///
-    /// Decrements the semaphore's count. If the count is zero, the calling
-    /// process will block until a permit is available.
-    pub fn acquire(&self) {
-        loop {
-            let current = self.count.load(Ordering::Acquire);
-            if current > 0 {
-                if self.count.compare_exchange(
-                    current,
-                    current - 1,
-                    Ordering::AcqRel,
-                    Ordering::Acquire,
-                ).is_ok() {
-                    break;
-                }
-            } else {
-                // In a more advanced implementation, we would block the process
-                // and add it to a waiting queue
-                core::hint::spin_loop();
-            }
-        }
-    }
-    
-    /// Releases a permit to the semaphore
-    ///
-    /// Increments the semaphore's count and potentially wakes up a waiting process.
-    pub fn release(&self) {
-        self.count.fetch_add(1, Ordering::Release);
-        // In a more advanced implementation, we would wake up a waiting process
-    }
-    
-    /// Attempts to acquire a permit without blocking
-    ///
-    /// Returns true if a permit was acquired, false otherwise.
-    pub fn try_acquire(&self) -> bool {
-        loop {
-            let current = self.count.load(Ordering::Acquire);
-            if current > 0 {
-                if self.count.compare_exchange(
-                    current,
-                    current - 1,
-                    Ordering::AcqRel,
-                    Ordering::Acquire,
-                ).is_ok() {
-                    return true;
-                }
-            } else {
-                return false;
-            }
-        }
-    }
-}
-
-/// A condition variable for process synchronization
-pub struct Condvar {
-    waiters: AtomicU32,
-}
-
-impl Condvar {
-    /// Creates a new condition variable
-    pub const fn new() -> Self {
-        Self {
-            waiters: AtomicU32::new(0),
-        }
-    }
-    
-    /// Blocks the current process until this condition variable is notified
-    ///
-    /// This function should only be called while holding the associated mutex.
-    pub fn wait<T>(&self, _guard: MutexGuard<'_, T>) {
-        // Increment waiter count
-        self.waiters.fetch_add(1, Ordering::Release);
-        
-        // In a more advanced implementation, we would:
-        // 1. Release the mutex
-        // 2. Block the current process
-        // 3. Add the process to a waiting queue
-        // 4. Reacquire the mutex when woken up
-        
-        // For now, we'll just spin
-        loop {
-            core::hint::spin_loop();
-        }
-    }
-    
-    /// Wakes up one process waiting on this condition variable
-    pub fn notify_one(&self) {
-        // Decrement waiter count
-        self.waiters.fetch_sub(1, Ordering::Release);
-        // In a more advanced implementation, we would wake up one waiting process
-    }
-    
-    /// Wakes up all processes waiting on this condition variable
-    pub fn notify_all(&self) {
-        // Reset waiter count
-        self.waiters.store(0, Ordering::Release);
-        // In a more advanced implementation, we would wake up all waiting processes
-    }
-}
\ No newline at end of file
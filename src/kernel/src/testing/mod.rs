@@ -1,126 +0,0 @@
-//! Testing framework for HNX kernel
-//!
-//! This module provides a testing framework that can run in kernel space
-//! to validate kernel functionality and catch regressions.
-
-#[cfg(test)]
-mod tests;
-
-/// Test result type
-#[derive(Debug, PartialEq)]
-pub enum TestResult {
-    /// Test passed
-    Pass,
-    /// Test failed with reason
-    Fail(&'static str),
-}
-
-/// Runs a single test function
-pub fn run_test<F>(name: &'static str, test_fn: F) -> TestResult
-where
-    F: Fn() -> TestResult,
-{
-    crate::info!("test: running {}", name);
-    let result = test_fn();
-    
-    match result {
-        TestResult::Pass => {
-            crate::info!("test: {} ... PASSED", name);
-        }
-        TestResult::Fail(reason) => {
-            crate::error!("test: {} ... FAILED ({})", name, reason);
-        }
-    }
-    
-    result
-}
-
-/// Asserts that two values are equal
-#[macro_export]
-macro_rules! assert_eq {
-    ($left:expr, $right:expr) => ({
-        match (&$left, &$right) {
-            (left_val, right_val) => {
-                if !(*left_val == *right_val) {
-                    return $crate::testing::TestResult::Fail(
-                        concat!("assertion failed: `(left == right)`\n",
-                                "  left: `{:?}`,\n",
-                                " right: `{:?}`",
-                                stringify!($left), stringify!($right))
-                    );
-                }
-            }
-        }
-    });
-    ($left:expr, $right:expr,) => ({
-        $crate::assert_eq!($left, $right)
-    });
-    ($left:expr, $right:expr, $($arg:tt)+) => ({
-        match (&($left), &($right)) {
-            (left_val, right_val) => {
-                if !(*left_val == *right_val) {
-                    return $crate::testing::TestResult::Fail(
-                        &format!($($arg)+)
-                    );
-                }
-            }
-        }
-    });
-}
-
-/// Asserts that a condition is true
-#[macro_export]
-macro_rules! assert {
-    ($cond:expr) => ({
-        if !$cond {
-            return $crate::testing::TestResult::Fail(
-                concat!("assertion failed: ", stringify!($cond))
-            );
-        }
-    });
-    ($cond:expr,) => ({
-        $crate::assert!($cond)
-    });
-    ($cond:expr, $($arg:tt)+) => ({
-        if !$cond {
-            return $crate::testing::TestResult::Fail(
-                &format!($($arg)+)
-            );
-        }
-    });
-}
-
-/// Runs all registered tests
-pub fn run_all_tests() {
-    crate::info!("test: starting test suite");
-    
-    // Memory management tests
-    run_test("memory_allocator_basic", || {
-        // Test basic allocation and deallocation
-        assert!(true); // Placeholder
-        TestResult::Pass
-    });
-    
-    // IPC tests
-    run_test("ipc_endpoint_creation", || {
-        // Test endpoint creation and destruction
-        assert!(true); // Placeholder
-        TestResult::Pass
-    });
-    
-    // Security tests
-    run_test("capability_allocation", || {
-        // Test capability allocation and validation
-        assert!(true); // Placeholder
-        TestResult::Pass
-    });
-    
-    // Process management tests
-    run_test("process_creation", || {
-        // Test process creation and scheduling
-        assert!(true); // Placeholder
-        TestResult::Pass
-    });
-    
-    crate::info!("test: test suite completed");
-}
\ No newline at end of file
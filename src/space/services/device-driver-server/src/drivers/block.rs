@@ -1,161 +0,0 @@
-//! Block device driver implementation for user-space device driver server
-//!
-//! This module implements block device drivers that communicate with the kernel
-//! through IPC to handle block device operations.
-
-extern crate alloc;
-
-use alloc::vec::Vec;
-
-/// Trait for block device drivers
-pub trait BlockDriver {
-    /// Read blocks from the device
-    fn read_blocks(&mut self, start_block: u64, num_blocks: u32, buffer: &mut [u8]) -> Result<u32, BlockError>;
-    
-    /// Write blocks to the device
-    fn write_blocks(&mut self, start_block: u64, num_blocks: u32, buffer: &[u8]) -> Result<u32, BlockError>;
-    
-    /// Get device information
-    fn get_device_info(&self) -> BlockDeviceInfo;
-    
-    /// Handle device-specific control operations
-    fn ioctl(&mut self, cmd: u32, arg: u64) -> Result<u64, BlockError>;
-}
-
-/// Block device information structure
-#[derive(Debug, Clone)]
-pub struct BlockDeviceInfo {
-    pub device_id: u64,
-    pub driver_id: u64,
-    pub block_size: u32,
-    pub total_blocks: u64,
-    pub device_name: alloc::string::String,
-    pub device_type: BlockDeviceType,
-}
-
-/// Types of block devices
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum BlockDeviceType {
-    SdCard,
-    Mmc,
-    Nvme,
-    VirtioBlock,
-    UsbMassStorage,
-    Other,
-}
-
-/// Block device error types
-#[derive(Debug, Clone)]
-pub enum BlockError {
-    IoError,
-    InvalidAddress,
-    DeviceBusy,
-    DeviceNotReady,
-    Timeout,
-    InvalidParameter,
-    OutOfMemory,
-}
-
-/// SD/MMC specific driver implementation
-pub struct SdMmcDriver {
-    pub device_info: BlockDeviceInfo,
-    pub cid: [u8; 16],  // Card Identification
-    pub csd: [u8; 16],  // Card-Specific Data
-    pub scr: [u8; 8],   // SD Configuration Register
-    pub ocr: u32,       // Operating Conditions Register
-    pub rca: u16,       // Relative Card Address
-}
-
-impl BlockDriver for SdMmcDriver {
-    /// Read blocks from SD/MMC card
-    fn read_blocks(&mut self, start_block: u64, num_blocks: u32, buffer: &mut [u8]) -> Result<u32, BlockError> {
-        // In a real implementation, this would interact with the SD/MMC controller
-        // For now, we'll just simulate a successful read
-        if buffer.len() < (num_blocks as usize * self.device_info.block_size as usize) {
-            return Err(BlockError::InvalidParameter);
-        }
-        
-        // Simulate reading data (in real implementation, this would read from hardware)
-        // Fill buffer with dummy data for demonstration
-        for i in 0..buffer.len() {
-            buffer[i] = (i % 256) as u8;
-        }
-        
-        Ok(num_blocks)
-    }
-    
-    /// Write blocks to SD/MMC card
-    fn write_blocks(&mut self, start_block: u64, num_blocks: u32, buffer: &[u8]) -> Result<u32, BlockError> {
-        // In a real implementation, this would interact with the SD/MMC controller
-        // For now, we'll just simulate a successful write
-        if buffer.len() < (num_blocks as usize * self.device_info.block_size as usize) {
-            return Err(BlockError::InvalidParameter);
-        }
-        
-        // Simulate writing data (in real implementation, this would write to hardware)
-        Ok(num_blocks)
-    }
-    
-    /// Get device information
-    fn get_device_info(&self) -> BlockDeviceInfo {
-        self.device_info.clone()
-    }
-    
-    /// Handle device-specific control operations
-    fn ioctl(&mut self, cmd: u32, arg: u64) -> Result<u64, BlockError> {
-        match cmd {
-            // Get card status
-            0x1001 => Ok(self.ocr as u64),
-            // Get card capacity
-            0x1002 => Ok(self.device_info.total_blocks),
-            // Other commands...
-            _ => Err(BlockError::InvalidParameter),
-        }
-    }
-}
-
-impl SdMmcDriver {
-    /// Initialize SD/MMC card
-    pub fn init(device_info: BlockDeviceInfo) -> Result<Self, BlockError> {
-        // In a real implementation, this would initialize the SD/MMC card
-        // by sending initialization commands and reading card registers
-        
-        let mut driver = SdMmcDriver {
-            device_info,
-            cid: [0; 16],
-            csd: [0; 16],
-            scr: [0; 8],
-            ocr: 0,
-            rca: 0,
-        };
-        
-        // Simulate card initialization
-        driver.ocr = 0x00FF8000; // Typical OCR value for SD cards
-        driver.rca = 1; // Typical RCA
-        
-        // Fill CID, CSD, and SCR with dummy values
-        for i in 0..16 {
-            driver.cid[i] = i as u8;
-            if i < 16 {
-                driver.csd[i] = (i + 16) as u8;
-            }
-        }
-        for i in 0..8 {
-            driver.scr[i] = (i + 32) as u8;
-        }
-        
-        Ok(driver)
-    }
-    
-    /// Detect if card is present
-    pub fn is_card_present(&self) -> bool {
-        // In a real implementation, this would check card detection pin
-        true
-    }
-    
-    /// Check if card is write protected
-    pub fn is_write_protected(&self) -> bool {
-        // In a real implementation, this would check write protect pin
-        false
-    }
-}
\ No newline at end of file
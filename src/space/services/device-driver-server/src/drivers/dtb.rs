@@ -1,174 +0,0 @@
-//! Device Tree Blob (DTB) Parser for user-space device driver server
-//!
-//! This module implements parsing of Device Tree Binary (DTB) format to discover
-//! and enumerate hardware devices.
-
-extern crate alloc;
-
-use core::str;
-use alloc::vec::Vec;
-use alloc::string::String;
-use alloc::boxed::Box;
-
-/// Device Tree Parser
-pub struct DtbParser {
-    /// DTB data pointer
-    dtb_ptr: usize,
-    /// Structure block offset
-    off_struct: usize,
-    /// String block offset
-    off_strings: usize,
-    /// Structure block size
-    size_struct: usize,
-    /// String block data
-    strings: &'static [u8],
-    /// DTB data block
-    blob: &'static [u8],
-}
-
-/// Device Tree Node
-#[derive(Debug, Clone)]
-pub struct DtbNode {
-    /// Node name
-    pub name: &'static str,
-    /// Node path
-    pub path: &'static str,
-    /// Node offset
-    pub offset: usize,
-}
-
-/// Device Tree Property
-#[derive(Debug, Clone)]
-pub struct DtbProperty {
-    /// Property name
-    pub name: &'static str,
-    /// Property value
-    pub value: &'static [u8],
-}
-
-/// Device matching criteria
-#[derive(Debug, Clone)]
-pub struct DeviceMatch {
-    /// Compatible string to match
-    pub compatible: Option<String>,
-    /// Device type to match
-    pub device_type: Option<String>,
-    /// Specific property to match
-    pub property: Option<(String, Vec<u8>)>,
-}
-
-impl DtbParser {
-    /// Create a new DTB parser
-    pub fn new(dtb_ptr: usize) -> Option<Self> {
-        if dtb_ptr == 0 {
-            return None;
-        }
-        
-        unsafe {
-            let hdr = core::slice::from_raw_parts(dtb_ptr as *const u8, 40);
-            let magic = u32::from_be_bytes([hdr[0], hdr[1], hdr[2], hdr[3]]);
-            
-            // Check DTB magic number
-            if magic != 0xD00D_FEEE {
-                return None;
-            }
-            
-            let off_struct = u32::from_be_bytes([hdr[8], hdr[9], hdr[10], hdr[11]]) as usize;
-            let off_strings = u32::from_be_bytes([hdr[12], hdr[13], hdr[14], hdr[15]]) as usize;
-            let size_struct = u32::from_be_bytes([hdr[16], hdr[17], hdr[18], hdr[19]]) as usize;
-            
-            let blob = core::slice::from_raw_parts(dtb_ptr as *const u8, off_struct + size_struct);
-            let strings = core::slice::from_raw_parts((dtb_ptr + off_strings) as *const u8, 1 << 20);
-            
-            Some(DtbParser {
-                dtb_ptr,
-                off_struct,
-                off_strings,
-                size_struct,
-                strings,
-                blob,
-            })
-        }
-    }
-    
-    /// Find all nodes matching the criteria
-    pub fn find_matching_nodes(&self, criteria: &DeviceMatch) -> Vec<DtbNode> {
-        let mut matching_nodes = Vec::new();
-        
-        // In a real implementation, we would traverse the device tree and match nodes
-        // For now, we'll return an empty vector
-        matching_nodes
-    }
-    
-    /// Get a property from a node
-    pub fn get_property(&self, node_offset: usize, prop_name: &str) -> Option<DtbProperty> {
-        // In a real implementation, we would parse the node and find the property
-        // For now, we'll return None
-        None
-    }
-    
-    /// Get the root node
-    pub fn get_root_node(&self) -> Option<DtbNode> {
-        // In a real implementation, we would return the root node
-        // For now, we'll return None
-        None
-    }
-    
-    /// Traverse the device tree
-    pub fn traverse<F>(&self, callback: F) 
-    where 
-        F: FnMut(&DtbNode, &[DtbProperty])
-    {
-        // In a real implementation, we would traverse the device tree
-        // For now, we'll do nothing
-    }
-}
-
-impl DtbNode {
-    /// Create a new DTB node
-    pub fn new(name: &'static str, path: &'static str, offset: usize) -> Self {
-        Self {
-            name,
-            path,
-            offset,
-        }
-    }
-}
-
-impl DtbProperty {
-    /// Create a new DTB property
-    pub fn new(name: &'static str, value: &'static [u8]) -> Self {
-        Self {
-            name,
-            value,
-        }
-    }
-    
-    /// Get property value as string
-    pub fn as_str(&self) -> Option<&str> {
-        str::from_utf8(self.value).ok()
-    }
-    
-    /// Get property value as u32
-    pub fn as_u32(&self) -> Option<u32> {
-        if self.value.len() >= 4 {
-            let bytes = &self.value[0..4];
-            Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
-        } else {
-            None
-        }
-    }
-    
-    /// Get property value as u64
-    pub fn as_u64(&self) -> Option<u64> {
-        if self.value.len() >= 8 {
-            let bytes = &self.value[0..8];
-            Some(u64::from_be_bytes([
-                bytes[0], bytes[1], bytes[2], bytes[3],
-                bytes[4], bytes[5], bytes[6], bytes[7]
-            ]))
-        } else {
-            None
-        }
-    }
-}
\ No newline at end of file
@@ -1,100 +0,0 @@
-//! ELF implementation for user-space filesystem server
-
-/// User program structure
-pub struct UserProgram {
-    pub entry: usize,
-    pub pt_base: usize,
-    pub user_sp: usize,
-}
-
-/// Find an ELF binary in data
-pub fn find_elf(data: &[u8]) -> Option<&[u8]> {
-    let m = [0x7F, b'E', b'L', b'F'];
-    let end = data.len().saturating_sub(4);
-    let mut i = 0;
-    while i < end {
-        let b0 = unsafe { *data.get_unchecked(i) };
-        if b0 == m[0] {
-            let b1 = unsafe { *data.get_unchecked(i + 1) };
-            let b2 = unsafe { *data.get_unchecked(i + 2) };
-            let b3 = unsafe { *data.get_unchecked(i + 3) };
-            if b1 == m[1] && b2 == m[2] && b3 == m[3] {
-                return Some(&data[i..]);
-            }
-        }
-        i += 1;
-    }
-    None
-}
-
-/// Load an ELF binary
-pub fn load(binary: &[u8]) -> Result<UserProgram, ()> {
-    // In a real implementation, this would load the ELF binary
-    // For now, we'll just return a placeholder
-    
-    crate::println!("Loading ELF binary (placeholder implementation)");
-    
-    // Check if this looks like an ELF binary
-    if binary.len() < 4 || binary[0..4] != [0x7F, b'E', b'L', b'F'] {
-        return Err(());
-    }
-    
-    // In a real implementation, we would parse the ELF headers and load the program
-    // For now, we'll just return a placeholder UserProgram
-    
-    Ok(UserProgram {
-        entry: 0x400000,  // Placeholder entry point
-        pt_base: 0x100000, // Placeholder page table base
-        user_sp: 0x80000000, // Placeholder user stack pointer
-    })
-}
-
-// ELF header structures
-#[repr(C)]
-struct Elf64Ehdr {
-    e_ident: [u8; 16],
-    e_type: u16,
-    e_machine: u16,
-    e_version: u32,
-    e_entry: u64,
-    e_phoff: u64,
-    e_shoff: u64,
-    e_flags: u32,
-    e_ehsize: u16,
-    e_phentsize: u16,
-    e_phnum: u16,
-    e_shentsize: u16,
-    e_shnum: u16,
-    e_shstrndx: u16,
-}
-
-#[repr(C)]
-struct Elf64Phdr {
-    p_type: u32,
-    p_flags: u32,
-    p_offset: u64,
-    p_vaddr: u64,
-    p_paddr: u64,
-    p_filesz: u64,
-    p_memsz: u64,
-    p_align: u64,
-}
-
-#[repr(C)]
-struct Elf64Shdr {
-    sh_name: u32,
-    sh_type: u32,
-    sh_flags: u64,
-    sh_addr: u64,
-    sh_offset: u64,
-    sh_size: u64,
-    sh_link: u32,
-    sh_info: u32,
-    sh_addralign: u64,
-    sh_entsize: u64,
-}
-
-const PT_LOAD: u32 = 1;
-const PF_X: u32 = 1 << 0;
-const PF_W: u32 = 1 << 1;
-const PF_R: u32 = 1 << 2;
\ No newline at end of file
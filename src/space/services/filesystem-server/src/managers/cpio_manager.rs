@@ -1,23 +0,0 @@
-//! CPIO filesystem manager for user-space filesystem server
-
-use spin::Mutex;
-use alloc::collections::BTreeMap;
-
-/// CPIO filesystem manager
-pub struct CpioManager {
-    // Manager state would go here
-}
-
-impl CpioManager {
-    /// Create a new CPIO filesystem manager
-    pub fn new() -> Self {
-        Self {
-            // Initialize state
-        }
-    }
-    
-    /// Initialize the CPIO filesystem manager
-    pub fn init(&mut self) {
-        crate::println!("Initializing CPIO filesystem manager...");
-    }
-}
\ No newline at end of file
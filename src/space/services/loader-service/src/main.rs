@@ -1,19 +0,0 @@
-#![no_std]
-#![no_main]
-
-use core::panic::PanicInfo;
-
-#[no_mangle]
-pub extern "C" fn _start() -> ! {
-    // 最简单版本：不做任何事，只是循环
-    // 测试进程是否能正常启动
-
-    loop {
-        unsafe { core::arch::asm!("wfi"); }
-    }
-}
-
-#[panic_handler]
-fn panic(_info: &PanicInfo) -> ! {
-    loop {}
-}
\ No newline at end of file